@@ -0,0 +1,123 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rust_nmea::{parser::Parser, stream::StreamDecoder};
+use std::hint::black_box;
+
+/// One epoch of a u-blox receiver with a heading sensor and a proprietary sentence
+const CORPUS: &str = include_str!("corpus.nmea");
+
+fn parse(c: &mut Criterion) {
+    let lines: Vec<&str> = CORPUS.lines().collect();
+    let mut group = c.benchmark_group("parse");
+    group.throughput(Throughput::Bytes(CORPUS.len() as u64));
+
+    group.bench_function("parse_line", |b| {
+        b.iter(|| {
+            for line in &lines {
+                black_box(Parser::parse_line(black_box(line)).unwrap());
+            }
+        })
+    });
+    group.bench_function("parse_borrowed", |b| {
+        b.iter(|| {
+            for line in &lines {
+                black_box(Parser::parse_borrowed(black_box(line)).unwrap());
+            }
+        })
+    });
+    group.bench_function("stream_decoder", |b| {
+        let mut decoder = StreamDecoder::new();
+        b.iter(|| {
+            for sentence in decoder.sentences(black_box(CORPUS.as_bytes())) {
+                black_box(sentence.unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+/// Sentences 1.2 could parse, the rest of the corpus was added later
+const V1_SENTENCES: usize = 7;
+
+/// Reduced copy of the 1.2 parse path: its framing loop verbatim, then every
+/// field converted with `str::parse` or copied into a `String` like its
+/// sentence parsers did. Sentence types are not built, so this is a lower bound
+/// of what 1.2 spent per line.
+#[allow(clippy::double_ended_iterator_last)]
+fn parse_line_v1(line: &str) -> Option<(String, Vec<String>)> {
+    let checksum = u8::from_str_radix(line.split('*').last()?, 16).ok()?;
+    let command_clean = line.split('$').last()?;
+    let command_clean = command_clean.split('*').collect::<Vec<_>>()[0];
+    let mut checksum_calculated = 0;
+    for c in command_clean.chars() {
+        checksum_calculated ^= c as u8;
+    }
+    if checksum_calculated != checksum {
+        return None;
+    }
+
+    let mut command = String::new();
+    let mut commands: Vec<String> = Vec::new();
+    let mut sentence = String::new();
+    let (mut type_start_collected, mut command_type_collected) = (false, false);
+    for char in line.chars() {
+        command += &char.to_string();
+        if !type_start_collected {
+            if command.len() == 3 {
+                type_start_collected = true;
+                command = "".to_string();
+            }
+        } else if !command_type_collected {
+            if command.len() == 4 && char == ',' {
+                sentence = command.replace(',', "");
+                command_type_collected = true;
+                command = "".to_string();
+            }
+        } else if char == ',' {
+            commands.push(command.replace(',', ""));
+            command = "".to_string();
+        } else if char == '*' {
+            commands.push(command.replace([',', '*'], ""));
+            command = "".to_string();
+            break;
+        }
+    }
+    if !command.is_empty() {
+        commands.push(command);
+    }
+
+    for field in &commands {
+        if field.contains('.') {
+            black_box(field.parse::<f64>().ok());
+        } else if let Ok(number) = field.parse::<u32>() {
+            black_box(number);
+        } else {
+            black_box(field.to_string());
+        }
+    }
+    Some((sentence, commands))
+}
+
+/// Current parser against the 1.2 parse path on the sentences both support
+fn baseline(c: &mut Criterion) {
+    let lines: Vec<&str> = CORPUS.lines().take(V1_SENTENCES).collect();
+    let mut group = c.benchmark_group("baseline");
+
+    group.bench_function("parse_line_v1", |b| {
+        b.iter(|| {
+            for line in &lines {
+                black_box(parse_line_v1(black_box(line)).unwrap());
+            }
+        })
+    });
+    group.bench_function("parse_line", |b| {
+        b.iter(|| {
+            for line in &lines {
+                black_box(Parser::parse_line(black_box(line)).unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse, baseline);
+criterion_main!(benches);
@@ -0,0 +1,285 @@
+use std::fs::File;
+use std::io;
+use std::ops::AddAssign;
+use std::path::Path;
+
+use memmap2::Mmap;
+use rayon::prelude::*;
+
+use crate::{
+    parser::Parser,
+    types::{CommandTypes, Error, ErrorKind},
+};
+
+/// Default chunk size in bytes, chunks end at the first line boundary after it
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 20;
+
+/// Line and error counts of a chunk or a whole batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseStats {
+    /// Number of lines
+    pub lines: usize,
+    /// Lines parsed successfully
+    pub parsed: usize,
+    /// Lines failing checksum validation
+    pub checksum_errors: usize,
+    /// Lines that are not well formed sentences
+    pub framing_errors: usize,
+    /// Lines failing to parse for any other reason, e.g. invalid fields
+    pub parse_errors: usize,
+}
+
+impl ParseStats {
+    /// Number of lines failing to parse
+    pub fn errors(&self) -> usize {
+        self.checksum_errors + self.framing_errors + self.parse_errors
+    }
+
+    fn count(&mut self, result: &Result<CommandTypes, Error>) {
+        self.lines += 1;
+        match result {
+            Ok(_) => self.parsed += 1,
+            Err(e) => match e.kind {
+                ErrorKind::ChecksumMismatch { .. } => self.checksum_errors += 1,
+                ErrorKind::Framing(_) => self.framing_errors += 1,
+                _ => self.parse_errors += 1,
+            },
+        }
+    }
+}
+
+impl AddAssign for ParseStats {
+    fn add_assign(&mut self, other: ParseStats) {
+        self.lines += other.lines;
+        self.parsed += other.parsed;
+        self.checksum_errors += other.checksum_errors;
+        self.framing_errors += other.framing_errors;
+        self.parse_errors += other.parse_errors;
+    }
+}
+
+/// Parse results of a chunk of consecutive lines
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk {
+    /// Byte offset of the chunk start in the source
+    pub byte_offset: u64,
+    /// Line number of the first line of the chunk, starting from 1
+    pub first_line: usize,
+    /// Result of each line in order, the same as [`Parser::parse_line`] gives
+    pub results: Vec<Result<CommandTypes, Error>>,
+    /// Line and error counts of the chunk
+    pub stats: ParseStats,
+}
+
+/// Parse results of a whole source, chunks are in their original order
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Batch {
+    /// Parsed chunks
+    pub chunks: Vec<Chunk>,
+}
+
+impl Batch {
+    /// Results of all lines in their original order
+    pub fn results(&self) -> impl Iterator<Item = &Result<CommandTypes, Error>> {
+        self.chunks.iter().flat_map(|chunk| chunk.results.iter())
+    }
+
+    /// Line and error counts of all chunks
+    pub fn stats(&self) -> ParseStats {
+        let mut stats = ParseStats::default();
+        for chunk in &self.chunks {
+            stats += chunk.stats;
+        }
+        stats
+    }
+}
+
+/// Splits a source into chunks at line boundaries and parses them in parallel on the rayon thread pool
+///
+/// Lines are separated by `\n` with an optional `\r`, the same as [`str::lines`], so every line
+/// including blank ones has a result. [`BatchParser::parse`] keeps all results, use
+/// [`BatchParser::for_each`] to handle them as they are parsed.
+/// # Examples
+/// ```
+/// use rust_nmea::{batch::BatchParser, parser::Parser};
+/// let log = "$GPZDA,023012.00,01,03,2024,-05,00*4A\r\n$GPZDA,broken*00\r\n$SDDPT,2.4,0.5*54\r\n".repeat(100);
+/// let batch = BatchParser::new().chunk_size(256).parse(log.as_bytes());
+/// assert!(batch.chunks.len() > 1);
+/// assert!(batch.results().eq(log.lines().map(Parser::parse_line).collect::<Vec<_>>().iter()));
+/// let stats = batch.stats();
+/// assert_eq!(stats.lines, 300);
+/// assert_eq!(stats.parsed, 200);
+/// assert_eq!(stats.checksum_errors, 100);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BatchParser {
+    chunk_size: usize,
+}
+
+impl Default for BatchParser {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+}
+
+impl BatchParser {
+    /// Create parser with [`DEFAULT_CHUNK_SIZE`]
+    pub fn new() -> BatchParser {
+        BatchParser::default()
+    }
+
+    /// Set chunk size in bytes, chunks are extended to the next line boundary
+    pub fn chunk_size(mut self, chunk_size: usize) -> BatchParser {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Memory-map a log file and parse it
+    pub fn parse_file<P: AsRef<Path>>(&self, path: P) -> io::Result<Batch> {
+        with_mapped_file(path, |bytes| self.parse(bytes))
+    }
+
+    /// Memory-map a log file and call `f` with each line number and result, see [`BatchParser::for_each`]
+    pub fn for_each_file<P, F>(&self, path: P, f: F) -> io::Result<ParseStats>
+    where
+        P: AsRef<Path>,
+        F: Fn(usize, Result<CommandTypes, Error>) + Sync + Send,
+    {
+        with_mapped_file(path, |bytes| self.for_each(bytes, f))
+    }
+
+    /// Parse lines of given bytes and call `f` with the line number, starting from 1, and result of each
+    ///
+    /// Results are not kept, only the line and error counts are returned. Chunks are parsed in
+    /// parallel, so `f` gets the lines of a chunk in order but chunks in any order.
+    /// # Examples
+    /// ```
+    /// use std::sync::Mutex;
+    /// use rust_nmea::batch::BatchParser;
+    /// let log = "$GPZDA,023012.00,01,03,2024,-05,00*4A\r\n$GPZDA,broken*00\r\n".repeat(100);
+    /// let failing = Mutex::new(Vec::new());
+    /// let stats = BatchParser::new().chunk_size(256).for_each(log.as_bytes(), |line_number, result| {
+    ///     if result.is_err() {
+    ///         failing.lock().unwrap().push(line_number);
+    ///     }
+    /// });
+    /// let mut failing = failing.into_inner().unwrap();
+    /// failing.sort();
+    /// assert_eq!(failing, (1..=100).map(|n| 2 * n).collect::<Vec<_>>());
+    /// assert_eq!(stats.parsed, 100);
+    /// ```
+    pub fn for_each<F>(&self, bytes: &[u8], f: F) -> ParseStats
+    where
+        F: Fn(usize, Result<CommandTypes, Error>) + Sync + Send,
+    {
+        let chunks = self.split(bytes);
+        let line_counts: Vec<usize> = chunks
+            .par_iter()
+            .map(|(_, chunk)| line_count(chunk))
+            .collect();
+        let first_lines: Vec<usize> = line_counts
+            .iter()
+            .scan(1, |line_number, count| {
+                let first_line = *line_number;
+                *line_number += count;
+                Some(first_line)
+            })
+            .collect();
+
+        chunks
+            .into_par_iter()
+            .zip(first_lines)
+            .map(|((_, chunk), first_line)| {
+                let mut stats = ParseStats::default();
+                for (index, line) in lines(chunk).enumerate() {
+                    let result = parse_line(line);
+                    stats.count(&result);
+                    f(first_line + index, result);
+                }
+                stats
+            })
+            .reduce(ParseStats::default, |mut total, stats| {
+                total += stats;
+                total
+            })
+    }
+
+    /// Parse lines of given bytes
+    pub fn parse(&self, bytes: &[u8]) -> Batch {
+        let mut chunks: Vec<Chunk> = self
+            .split(bytes)
+            .into_par_iter()
+            .map(|(byte_offset, chunk)| parse_chunk(byte_offset, chunk))
+            .collect();
+
+        // Line numbers are known once the lines of preceding chunks are counted
+        let mut line_number = 1;
+        for chunk in &mut chunks {
+            chunk.first_line = line_number;
+            line_number += chunk.stats.lines;
+        }
+        Batch { chunks }
+    }
+
+    /// Split given bytes into chunks of at least the chunk size ending after a `\n`
+    fn split<'a>(&self, bytes: &'a [u8]) -> Vec<(usize, &'a [u8])> {
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start < bytes.len() {
+            let end = (start + self.chunk_size).min(bytes.len());
+            let end = match bytes[end - 1..].iter().position(|byte| *byte == b'\n') {
+                Some(position) => end + position,
+                None => bytes.len(),
+            };
+            chunks.push((start, &bytes[start..end]));
+            start = end;
+        }
+        chunks
+    }
+}
+
+fn with_mapped_file<P: AsRef<Path>, T>(path: P, f: impl FnOnce(&[u8]) -> T) -> io::Result<T> {
+    let file = File::open(path)?;
+    // SAFETY: the map is only read and dropped before returning, the file must not be
+    // truncated by another process meanwhile
+    let map = unsafe { Mmap::map(&file)? };
+    Ok(f(&map))
+}
+
+/// Lines of a chunk without their line endings
+fn lines(chunk: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let lines = chunk.strip_suffix(b"\n").unwrap_or(chunk);
+    lines
+        .split(|byte| *byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Number of lines of a non-empty chunk, the same as `lines(chunk).count()`
+fn line_count(chunk: &[u8]) -> usize {
+    let line_endings = chunk.iter().filter(|byte| **byte == b'\n').count();
+    line_endings + usize::from(!chunk.ends_with(b"\n"))
+}
+
+fn parse_line(line: &[u8]) -> Result<CommandTypes, Error> {
+    Parser::parse_bytes(line).map(|sentence| sentence.body)
+}
+
+fn parse_chunk(byte_offset: usize, chunk: &[u8]) -> Chunk {
+    let mut stats = ParseStats::default();
+    let results = lines(chunk)
+        .map(|line| {
+            let result = parse_line(line);
+            stats.count(&result);
+            result
+        })
+        .collect();
+
+    Chunk {
+        byte_offset: byte_offset as u64,
+        first_line: 0,
+        results,
+        stats,
+    }
+}
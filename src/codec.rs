@@ -0,0 +1,178 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BufMut, BytesMut};
+use futures_core::Stream;
+use tokio::io::AsyncRead;
+use tokio_util::codec::{Decoder, Encoder, FramedRead};
+
+use crate::{
+    encoder::{encode_to, Encode},
+    parser::Registry,
+    stream::StreamDecoder,
+    types::{Error, Sentence, TalkerIds},
+};
+
+/// Tokio codec framing and parsing sentences, built on [`StreamDecoder`]
+///
+/// Decoded items are `Result<Sentence, Error>` so a sentence failing to parse does not end the stream,
+/// the codec error is only used for I/O errors. Sentence types implementing [`Encode`] are encoded as
+/// checksummed lines with the talker ID of the codec, text lines are written as given followed by `\r\n`.
+/// # Examples
+/// ```
+/// use bytes::BytesMut;
+/// use tokio_util::codec::Encoder;
+/// use rust_nmea::{codec::NmeaCodec, commands::hdt::HDT, types::TalkerIds};
+/// let mut codec = NmeaCodec::new().talker_id(TalkerIds::HE);
+/// let mut buffer = BytesMut::new();
+/// codec.encode(HDT { heading: Some(274.07) }, &mut buffer).unwrap();
+/// codec.encode("$SDDPT,2.4,0.5*54", &mut buffer).unwrap();
+/// assert_eq!(&buffer[..], b"$HEHDT,274.07,T*19\r\n$SDDPT,2.4,0.5*54\r\n");
+/// ```
+#[derive(Debug)]
+pub struct NmeaCodec {
+    decoder: StreamDecoder,
+    talker_id: TalkerIds,
+}
+
+impl Default for NmeaCodec {
+    fn default() -> NmeaCodec {
+        NmeaCodec::with_decoder(StreamDecoder::default())
+    }
+}
+
+impl NmeaCodec {
+    /// Create codec with default maximum sentence length, encoding sentences with talker ID `GP`
+    pub fn new() -> NmeaCodec {
+        NmeaCodec::default()
+    }
+
+    /// Create codec with given [`StreamDecoder`], e.g. with another maximum length or a registry
+    pub fn with_decoder(decoder: StreamDecoder) -> NmeaCodec {
+        NmeaCodec {
+            decoder,
+            talker_id: TalkerIds::GP,
+        }
+    }
+
+    /// Set talker ID of encoded sentences
+    pub fn talker_id(mut self, talker_id: TalkerIds) -> NmeaCodec {
+        self.talker_id = talker_id;
+        self
+    }
+}
+
+impl Decoder for NmeaCodec {
+    type Item = Result<Sentence, Error>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let mut consumed = 0;
+        let mut decoded = None;
+        for byte in src.iter() {
+            consumed += 1;
+            decoded = self.decoder.push_byte(*byte);
+            if decoded.is_some() {
+                break;
+            }
+        }
+        src.advance(consumed);
+        Ok(decoded)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(decoded) => Ok(Some(decoded)),
+            None => Ok(self.decoder.finish()),
+        }
+    }
+}
+
+impl<T: Encode> Encoder<T> for NmeaCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        encode_to(&self.talker_id, &item, dst)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "sentence failed to encode"))
+    }
+}
+
+impl Encoder<&str> for NmeaCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: &str, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let item = item.trim_end_matches(['\r', '\n']);
+        dst.reserve(item.len() + 2);
+        dst.put_slice(item.as_bytes());
+        dst.put_slice(b"\r\n");
+        Ok(())
+    }
+}
+
+impl Encoder<String> for NmeaCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: String, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(item.as_str(), dst)
+    }
+}
+
+/// Stream of parsed sentences over an [`AsyncRead`] source
+///
+/// Outer result carries I/O errors, inner result carries errors of single sentences.
+/// # Examples
+/// ```
+/// use futures::StreamExt;
+/// use rust_nmea::{codec::NmeaStream, types::CommandTypes};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let source: &[u8] = b"$GPZDA,023012.00,01,03,2024,-05,00*4A\r\n$SDDPT,2.4,0.5*54";
+/// let mut stream = NmeaStream::new(source);
+/// let first = stream.next().await.unwrap().unwrap().unwrap();
+/// assert!(matches!(first.body, CommandTypes::ZDA(_)));
+/// let second = stream.next().await.unwrap().unwrap().unwrap();
+/// assert_eq!(second.sentence_id, "DPT");
+/// assert!(stream.next().await.is_none());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct NmeaStream<R> {
+    inner: FramedRead<R, NmeaCodec>,
+}
+
+impl<R: AsyncRead> NmeaStream<R> {
+    /// Create stream over given source
+    pub fn new(source: R) -> NmeaStream<R> {
+        NmeaStream::with_codec(source, NmeaCodec::new())
+    }
+
+    /// Create stream over given source with given codec
+    pub fn with_codec(source: R, codec: NmeaCodec) -> NmeaStream<R> {
+        NmeaStream {
+            inner: FramedRead::new(source, codec),
+        }
+    }
+
+    /// Create stream parsing sentences with given registry, see [`Registry`]
+    pub fn with_registry(source: R, registry: Registry) -> NmeaStream<R> {
+        NmeaStream::with_codec(
+            source,
+            NmeaCodec::with_decoder(StreamDecoder::new().registry(registry)),
+        )
+    }
+
+    /// Consume the stream and return its source
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for NmeaStream<R> {
+    type Item = io::Result<Result<Sentence, Error>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
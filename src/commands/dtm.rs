@@ -1,69 +1,59 @@
-use crate::types::{CardinalDirection, Command, Error};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, CardinalDirection, Command, Error, FieldContext,
+    InlineString, OptionalField,
+};
 
 /// DTM (Datum Reference)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct DTM {
     /// Local datum
-    pub local_datum_code: String,
+    pub local_datum_code: InlineString<8>,
     /// Local datum sub division code
-    pub local_datum_sub_division_code: String,
+    pub local_datum_sub_division_code: InlineString<8>,
     /// Latitude offset, in minutes
-    pub latitude_offset: f64,
+    pub latitude_offset: Option<f64>,
     /// N=North/S=South indicator
-    pub northing_indicator: CardinalDirection,
+    pub northing_indicator: Option<CardinalDirection>,
     /// Longitude offset, in minutes
-    pub longitude_offset: f64,
+    pub longitude_offset: Option<f64>,
     /// E=East/W=West indicator
-    pub easting_indicator: CardinalDirection,
+    pub easting_indicator: Option<CardinalDirection>,
     /// Altitude offset, in meters
-    pub altitude_offset: f64,
+    pub altitude_offset: Option<f64>,
     /// Reference datum code
-    pub reference_datum_code: String,
-}
-
-impl Default for DTM {
-    fn default() -> Self {
-        Self {
-            local_datum_code: Default::default(),
-            local_datum_sub_division_code: Default::default(),
-            latitude_offset: Default::default(),
-            northing_indicator: CardinalDirection::North,
-            longitude_offset: Default::default(),
-            easting_indicator: CardinalDirection::East,
-            altitude_offset: Default::default(),
-            reference_datum_code: Default::default(),
-        }
-    }
+    pub reference_datum_code: InlineString<8>,
 }
 
 impl Command<DTM> for DTM {
-    fn parse_command(&self, command: Vec<String>) -> Result<DTM, Error> {
-        let local_datum_code = command[0].clone();
-        let local_datum_sub_division_code = command[1].clone();
-        let latitude_offset = command[2].parse::<f64>()?;
-        let northing_indicator = match command[3].chars().next() {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid northing indicator".to_string())),
-        };
-
-        let northing_indicator = match CardinalDirection::from_char(northing_indicator) {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid northing indicator".to_string())),
-        };
-
-        let longitude_offset = command[4].parse::<f64>()?;
-        let easting_indicator = match command[5].chars().next() {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-        };
-
-        let easting_indicator = match CardinalDirection::from_char(easting_indicator) {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-        };
+    fn parse_command(&self, command: &[&str]) -> Result<DTM, Error> {
+        if command.len() < 8 {
+            return Err(invalid_field_count(command));
+        }
 
-        let altitude_offset = command[6].parse::<f64>()?;
-        let reference_datum_code = command[7].clone();
+        let local_datum_code =
+            InlineString::try_from(command[0]).field(command, 0, "local_datum_code")?;
+        let local_datum_sub_division_code = InlineString::try_from(command[1]).field(
+            command,
+            1,
+            "local_datum_sub_division_code",
+        )?;
+        let latitude_offset = parse_field(command, 2, "latitude_offset").optional()?;
+        let northing_indicator = parse_code(
+            command,
+            3,
+            "northing_indicator",
+            CardinalDirection::from_str,
+        )
+        .optional()?;
+        let longitude_offset = parse_field(command, 4, "longitude_offset").optional()?;
+        let easting_indicator =
+            parse_code(command, 5, "easting_indicator", CardinalDirection::from_str).optional()?;
+        let altitude_offset = parse_field(command, 6, "altitude_offset").optional()?;
+        let reference_datum_code =
+            InlineString::try_from(command[7]).field(command, 7, "reference_datum_code")?;
 
         Ok(DTM {
             local_datum_code,
@@ -77,3 +67,18 @@ impl Command<DTM> for DTM {
         })
     }
 }
+
+impl Encode for DTM {
+    const SENTENCE_ID: &'static str = "DTM";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.field(self.local_datum_code)?;
+        fields.field(self.local_datum_sub_division_code)?;
+        fields.optional(self.latitude_offset)?;
+        fields.optional(self.northing_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.optional(self.longitude_offset)?;
+        fields.optional(self.easting_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.optional(self.altitude_offset)?;
+        fields.field(self.reference_datum_code)
+    }
+}
@@ -1,52 +1,50 @@
-use crate::types::{Command, Error, Time};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_field, Command, Error, FieldContext, OptionalField, Time,
+};
 
 /// GBS - GNSS Satellite Fault Detection
+///
+/// Fields are `None` when the receiver has no fault detection result
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct GBS {
     /// UTC Time
     pub time: Time,
     /// Expected error in latitude, in meters
-    pub latitude: f64,
+    pub latitude: Option<f64>,
     /// Expected error in longitude, in meters
-    pub longitude: f64,
+    pub longitude: Option<f64>,
     /// Expected error in altitude, in meters
-    pub altitude: f64,
+    pub altitude: Option<f64>,
     /// ID number of most likely failed satellite
-    pub failed_satellite_id: usize,
+    pub failed_satellite_id: Option<usize>,
     /// Probability of missed detection for most likely failed satellite
-    pub probability_of_missed_detection: f64,
+    pub probability_of_missed_detection: Option<f64>,
     /// Estimate of bias in meters on most likely failed satellite
-    pub estimate_of_bias: f64,
+    pub estimate_of_bias: Option<f64>,
     /// Standard deviation of bias estimate
-    pub standard_deviation_of_bias_estimate: f64,
+    pub standard_deviation_of_bias_estimate: Option<f64>,
 }
 
 impl Command<GBS> for GBS {
-    fn parse_command(&self, command: Vec<String>) -> Result<GBS, Error> {
-        let time_split: Vec<&str> = if command[0].contains('.') {
-            command[0].split('.').collect()
-        } else {
-            vec![&command[0], "0"]
-        };
-
-        let hour = time_split[0][..2].parse()?;
-        let minute = time_split[0][2..4].parse()?;
-        let second = time_split[0][4..6].parse()?;
-        let decimal_seconds = time_split[1].parse()?;
-        let time = Time {
-            hour,
-            minute,
-            second,
-            decimal_seconds,
-        };
-
-        let latitude = command[1].parse()?;
-        let longitude = command[2].parse()?;
-        let altitude = command[3].parse()?;
-        let failed_satellite_id = command[4].parse()?;
-        let probability_of_missed_detection = command[5].parse()?;
-        let estimate_of_bias = command[6].parse()?;
-        let standard_deviation_of_bias_estimate = command[7].parse()?;
+    fn parse_command(&self, command: &[&str]) -> Result<GBS, Error> {
+        if command.len() < 8 {
+            return Err(invalid_field_count(command));
+        }
+
+        let time = Time::from_str(command[0]).field(command, 0, "time")?;
+
+        let latitude = parse_field(command, 1, "latitude").optional()?;
+        let longitude = parse_field(command, 2, "longitude").optional()?;
+        let altitude = parse_field(command, 3, "altitude").optional()?;
+        let failed_satellite_id = parse_field(command, 4, "failed_satellite_id").optional()?;
+        let probability_of_missed_detection =
+            parse_field(command, 5, "probability_of_missed_detection").optional()?;
+        let estimate_of_bias = parse_field(command, 6, "estimate_of_bias").optional()?;
+        let standard_deviation_of_bias_estimate =
+            parse_field(command, 7, "standard_deviation_of_bias_estimate").optional()?;
 
         Ok(GBS {
             time,
@@ -60,3 +58,18 @@ impl Command<GBS> for GBS {
         })
     }
 }
+
+impl Encode for GBS {
+    const SENTENCE_ID: &'static str = "GBS";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.time(&self.time)?;
+        fields.optional(self.latitude)?;
+        fields.optional(self.longitude)?;
+        fields.optional(self.altitude)?;
+        fields.optional_with(self.failed_satellite_id, |fields, id| fields.padded(id, 2))?;
+        fields.optional(self.probability_of_missed_detection)?;
+        fields.optional(self.estimate_of_bias)?;
+        fields.optional(self.standard_deviation_of_bias_estimate)
+    }
+}
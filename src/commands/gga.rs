@@ -1,38 +1,45 @@
-use std::vec;
+use core::fmt;
 
-use crate::types::{CardinalDirection, Command, Cordinate, Error, GGAStatus, Time};
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_code, invalid_field_count, parse_code, parse_field, CardinalDirection, Command,
+    Cordinate, Error, FieldContext, GGAStatus, InlineString, Latitude, Longitude, OptionalField,
+    Time,
+};
 
 /// GGA (Global Positioning System Fix Data)
+///
+/// Fields a receiver without fix leaves empty are `None`
 #[derive(Debug, Clone, PartialEq)]
 pub struct GGA {
     /// UTC Time in hhmmss.ss format
-    pub time: Time,
+    pub time: Option<Time>,
     /// Latitude in ddmm.mmmmm format
-    pub lat: Cordinate,
+    pub lat: Option<Cordinate>,
     /// N=North/S=South indicator
-    pub northing_indicator: CardinalDirection,
+    pub northing_indicator: Option<CardinalDirection>,
     /// Longitude in dddmm.mmmmm format
-    pub lon: Cordinate,
+    pub lon: Option<Cordinate>,
     /// E=East/W=West indicator
-    pub easting_indicator: CardinalDirection,
+    pub easting_indicator: Option<CardinalDirection>,
     /// GPS Quality Indicator
     pub status: GGAStatus,
     /// Number of satellites in view
-    pub number_of_satellites: u8,
+    pub number_of_satellites: Option<u8>,
     /// Horizontal dilution of precision
-    pub horizontal_dilution_of_position: f64,
+    pub horizontal_dilution_of_position: Option<f64>,
     /// Antenna altitude above mean sea level
-    pub altitude: f64,
+    pub altitude: Option<f64>,
     /// Altitude units: M (meters, fixed field)
-    pub altitude_unit: String,
+    pub altitude_unit: Option<InlineString<4>>,
     /// Geoid separation: difference between ellipsoid and mean sea level
-    pub geoid_separation: f64,
+    pub geoid_separation: Option<f64>,
     /// Geoid separation units: M (meters, fixed field)
-    pub geoid_separation_unit: String,
+    pub geoid_separation_unit: Option<InlineString<4>>,
     /// Age of differential corrections (null when DGPS is not used)
-    pub differential_age_of_position: f32,
+    pub differential_age_of_position: Option<f32>,
     /// Differential reference station ID (null when DGPS is not used)
-    pub differential_reference_station_id: usize,
+    pub differential_reference_station_id: Option<usize>,
 }
 
 impl Default for GGA {
@@ -50,96 +57,86 @@ impl Default for GGA {
             geoid_separation_unit: Default::default(),
             differential_age_of_position: Default::default(),
             differential_reference_station_id: Default::default(),
-            northing_indicator: CardinalDirection::North,
-            easting_indicator: CardinalDirection::East,
+            northing_indicator: Default::default(),
+            easting_indicator: Default::default(),
         }
     }
 }
 
+impl GGA {
+    /// Latitude with its hemisphere, `None` without fix
+    pub fn latitude(&self) -> Option<Latitude> {
+        Some(Latitude {
+            cordinate: self.lat.clone()?,
+            direction: self.northing_indicator.clone()?,
+        })
+    }
+
+    /// Longitude with its hemisphere, `None` without fix
+    pub fn longitude(&self) -> Option<Longitude> {
+        Some(Longitude {
+            cordinate: self.lon.clone()?,
+            direction: self.easting_indicator.clone()?,
+        })
+    }
+}
+
 impl Command<GGA> for GGA {
-    fn parse_command(&self, command: Vec<String>) -> Result<GGA, Error> {
+    fn parse_command(&self, command: &[&str]) -> Result<GGA, Error> {
         if command.len() != 14 && command.len() != 13 {
-            Err(Error::ParseError(format!(
-                "Invalid command length for GGA: {}",
-                command.len()
-            )))
+            Err(invalid_field_count(command))
         } else {
-            let time_split: Vec<&str> = if command[0].contains('.') {
-                command[0].split('.').collect()
-            } else {
-                vec![&command[0], "0"]
-            };
+            let time = Time::from_str(command[0])
+                .field(command, 0, "time")
+                .optional()?;
+            let lat = Cordinate::from_str(command[1], 90)
+                .field(command, 1, "latitude")
+                .optional()?;
+            let northing_indicator = parse_code(
+                command,
+                2,
+                "northing_indicator",
+                CardinalDirection::from_str,
+            )
+            .optional()?;
+            let lon = Cordinate::from_str(command[3], 180)
+                .field(command, 3, "longitude")
+                .optional()?;
+            let easting_indicator =
+                parse_code(command, 4, "easting_indicator", CardinalDirection::from_str)
+                    .optional()?;
 
-            let hour = time_split[0][..2].parse()?;
-            let minute = time_split[0][2..4].parse()?;
-            let second = time_split[0][4..6].parse()?;
-            let decimal_seconds = time_split[1].parse()?;
-            let time = Time {
-                hour,
-                minute,
-                second,
-                decimal_seconds,
-            };
-
-            let latitude_degree: usize = command[1][..3].parse()?;
-            let latitude_minute = command[1][3..].parse()?;
-            let northing_indicator = match command[2].chars().next() {
-                Some(e) => e,
-                None => return Err(Error::ParseError("Invalid nothing indicator".to_string())),
-            };
-
-            let northing_indicator = match CardinalDirection::from_char(northing_indicator) {
-                Some(e) => e,
-                None => return Err(Error::ParseError("Invalid northing indicator".to_string())),
-            };
-
-            let longitude_degree: usize = command[3][..3].parse()?;
-            let longitude_minute = command[3][3..].parse()?;
-            let easting_indicator = match command[4].chars().next() {
-                Some(e) => e,
-                None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-            };
-
-            let easting_indicator = match CardinalDirection::from_char(easting_indicator) {
-                Some(e) => e,
-                None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-            };
-
-            let lat = Cordinate {
-                degree: latitude_degree,
-                minute: latitude_minute,
-            };
-
-            let lon = Cordinate {
-                degree: longitude_degree,
-                minute: longitude_minute,
-            };
-
-            let status: GGAStatus = match command[5].parse::<u8>()? {
+            let status: GGAStatus = match parse_field::<u8>(command, 5, "status")? {
                 0 => Ok(GGAStatus::Invalid),
                 1 => Ok(GGAStatus::S2d3D),
                 2 => Ok(GGAStatus::Dgnss),
                 3 => Ok(GGAStatus::FixedRtk),
                 4 => Ok(GGAStatus::FloatRtk),
                 5 => Ok(GGAStatus::DeadReckoning),
-                _ => Err(Error::ParseError(format!(
-                    "Invalid status for GGA: {}",
-                    command[5]
-                ))),
+                _ => Err(invalid_code(command, 5, "status")),
             }?;
-            let number_of_satellites: u8 = command[6].parse()?;
-            let horizontal_dilution_of_position = command[7].parse()?;
-            let altitude = command[8].parse()?;
-            let altitude_unit = command[9].to_string();
-            let geoid_separation = command[10].parse()?;
-            let geoid_separation_unit = command[11].to_string();
-            let differential_age_of_position = command[12].parse().unwrap_or_default();
-            let differential_reference_station_id = if command.len() == 14 || command[13].is_empty()
-            {
-                0
-            } else {
-                command[13].parse()?
+            let number_of_satellites =
+                parse_field(command, 6, "number_of_satellites").optional()?;
+            let horizontal_dilution_of_position =
+                parse_field(command, 7, "horizontal_dilution_of_position").optional()?;
+            let altitude = parse_field(command, 8, "altitude").optional()?;
+            let altitude_unit = match command[9] {
+                "" => None,
+                unit => Some(InlineString::try_from(unit).field(command, 9, "altitude_unit")?),
+            };
+            let geoid_separation = parse_field(command, 10, "geoid_separation").optional()?;
+            let geoid_separation_unit = match command[11] {
+                "" => None,
+                unit => Some(InlineString::try_from(unit).field(
+                    command,
+                    11,
+                    "geoid_separation_unit",
+                )?),
             };
+            let differential_age_of_position =
+                parse_field(command, 12, "differential_age_of_position").optional()?;
+            let differential_reference_station_id =
+                parse_field(command, 13, "differential_reference_station_id").optional()?;
 
             Ok(GGA {
                 time,
@@ -160,3 +157,26 @@ impl Command<GGA> for GGA {
         }
     }
 }
+
+impl Encode for GGA {
+    const SENTENCE_ID: &'static str = "GGA";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional_with(self.time.as_ref(), Fields::time)?;
+        fields.optional_with(self.lat.as_ref(), |fields, lat| fields.cordinate(lat, 2))?;
+        fields.optional(self.northing_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.optional_with(self.lon.as_ref(), |fields, lon| fields.cordinate(lon, 3))?;
+        fields.optional(self.easting_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.field(self.status.as_str())?;
+        fields.optional_with(self.number_of_satellites, |fields, e| fields.padded(e, 2))?;
+        fields.optional(self.horizontal_dilution_of_position)?;
+        fields.optional(self.altitude)?;
+        fields.optional(self.altitude_unit)?;
+        fields.optional(self.geoid_separation)?;
+        fields.optional(self.geoid_separation_unit)?;
+        fields.optional(self.differential_age_of_position)?;
+        fields.optional_with(self.differential_reference_station_id, |fields, e| {
+            fields.padded(e, 4)
+        })
+    }
+}
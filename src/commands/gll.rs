@@ -1,22 +1,30 @@
-use crate::types::{CardinalDirection, Command, Cordinate, Error, ModeIndicator, Status, Time};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, CardinalDirection, Command, Cordinate, Error, FieldContext,
+    Latitude, Longitude, ModeIndicator, OptionalField, Status, Time,
+};
 
 /// GLL ( Geographic Position - Latitude/Longitude )
+///
+/// Fields a receiver without fix leaves empty are `None`
 #[derive(Debug, Clone, PartialEq)]
 pub struct GLL {
     /// Latitude in ddmm.mmmm format
-    pub lat: Cordinate,
+    pub lat: Option<Cordinate>,
     /// N=North/S=South indicator
-    pub northing_indicator: CardinalDirection,
+    pub northing_indicator: Option<CardinalDirection>,
     /// Longitude in dddmm.mmmm format
-    pub lon: Cordinate,
+    pub lon: Option<Cordinate>,
     /// E=East/W=West indicator
-    pub easting_indicator: CardinalDirection,
+    pub easting_indicator: Option<CardinalDirection>,
     /// UTC Time in hhmmss.sss format
-    pub time: Time,
+    pub time: Option<Time>,
     /// Status
     pub status: Status,
-    /// Mode Indicator
-    pub mode_indicator: ModeIndicator,
+    /// Mode Indicator (NMEA 2.3 and later)
+    pub mode_indicator: Option<ModeIndicator>,
 }
 
 impl Default for GLL {
@@ -26,75 +34,58 @@ impl Default for GLL {
             lon: Default::default(),
             time: Default::default(),
             status: Status::Invalid,
-            mode_indicator: ModeIndicator::NoFix,
-            northing_indicator: CardinalDirection::North,
-            easting_indicator: CardinalDirection::East,
+            mode_indicator: Default::default(),
+            northing_indicator: Default::default(),
+            easting_indicator: Default::default(),
         }
     }
 }
 
-impl Command<GLL> for GLL {
-    fn parse_command(&self, command: Vec<String>) -> Result<GLL, crate::types::Error> {
-        let latitude_degree = command[0][..3].parse()?;
-        let latitude_minute = command[0][3..].parse()?;
-        let northing_indicator = match command[1].chars().next() {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid northing indicator".to_string())),
-        };
-
-        let northing_indicator = match CardinalDirection::from_char(northing_indicator) {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid northing indicator".to_string())),
-        };
-
-        let longitude_degree = command[2][..3].parse()?;
-        let longitude_minute = command[2][3..].parse()?;
-        let easting_indicator = match command[3].chars().next() {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-        };
-
-        let easting_indicator = match CardinalDirection::from_char(easting_indicator) {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-        };
-
-        let lat = Cordinate {
-            degree: latitude_degree,
-            minute: latitude_minute,
-        };
+impl GLL {
+    /// Latitude with its hemisphere, `None` without fix
+    pub fn latitude(&self) -> Option<Latitude> {
+        Some(Latitude {
+            cordinate: self.lat.clone()?,
+            direction: self.northing_indicator.clone()?,
+        })
+    }
 
-        let lon = Cordinate {
-            degree: longitude_degree,
-            minute: longitude_minute,
-        };
+    /// Longitude with its hemisphere, `None` without fix
+    pub fn longitude(&self) -> Option<Longitude> {
+        Some(Longitude {
+            cordinate: self.lon.clone()?,
+            direction: self.easting_indicator.clone()?,
+        })
+    }
+}
 
-        let time_split: Vec<&str> = if command[0].contains('.') {
-            command[0].split('.').collect()
-        } else {
-            vec![&command[0], "0"]
-        };
+impl Command<GLL> for GLL {
+    fn parse_command(&self, command: &[&str]) -> Result<GLL, Error> {
+        if command.len() < 6 {
+            return Err(invalid_field_count(command));
+        }
 
-        let hour = time_split[0][..2].parse::<u8>()?;
-        let minute = time_split[0][2..4].parse::<u8>()?;
-        let second = time_split[0][4..6].parse::<u8>()?;
-        let decimal_seconds = time_split[1].parse::<u8>()?;
-        let time = Time {
-            hour,
-            minute,
-            second,
-            decimal_seconds,
-        };
-        let status = match Status::from_str(&command[5]) {
-            Ok(e) => e,
-            Err(_) => {
-                return Err(Error::ParseError("Invalid status".to_string()));
-            }
-        };
-        let mode_indicator = match ModeIndicator::from_str(&command[6]) {
-            Ok(e) => e,
-            Err(_) => return Err(Error::ParseError("Invalid mode indicator".to_string())),
-        };
+        let lat = Cordinate::from_str(command[0], 90)
+            .field(command, 0, "latitude")
+            .optional()?;
+        let northing_indicator = parse_code(
+            command,
+            1,
+            "northing_indicator",
+            CardinalDirection::from_str,
+        )
+        .optional()?;
+        let lon = Cordinate::from_str(command[2], 180)
+            .field(command, 2, "longitude")
+            .optional()?;
+        let easting_indicator =
+            parse_code(command, 3, "easting_indicator", CardinalDirection::from_str).optional()?;
+        let time = Time::from_str(command[4])
+            .field(command, 4, "time")
+            .optional()?;
+        let status = parse_code(command, 5, "status", Status::from_str)?;
+        let mode_indicator =
+            parse_code(command, 6, "mode_indicator", ModeIndicator::from_str).optional()?;
 
         Ok(GLL {
             lat,
@@ -107,3 +98,21 @@ impl Command<GLL> for GLL {
         })
     }
 }
+
+impl Encode for GLL {
+    const SENTENCE_ID: &'static str = "GLL";
+
+    /// The mode indicator field is left out when it is `None`, as before NMEA 2.3
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional_with(self.lat.as_ref(), |fields, lat| fields.cordinate(lat, 2))?;
+        fields.optional(self.northing_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.optional_with(self.lon.as_ref(), |fields, lon| fields.cordinate(lon, 3))?;
+        fields.optional(self.easting_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.optional_with(self.time.as_ref(), Fields::time)?;
+        fields.field(self.status.as_str())?;
+        match &self.mode_indicator {
+            Some(mode_indicator) => fields.field(mode_indicator.as_str()),
+            None => Ok(()),
+        }
+    }
+}
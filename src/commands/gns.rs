@@ -1,16 +1,23 @@
-use crate::types::{CardinalDirection, Command, Error, ModeIndicator, Time};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_code, invalid_field_count, parse_code, parse_field, CardinalDirection, Command,
+    Cordinate, Error, FieldContext, InlineString, Latitude, Longitude, ModeIndicator,
+    OptionalField, Time,
+};
 
 /// GNS - GNSS Fix Data
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct GNS {
     /// UTC Time
     pub time: Time,
-    /// Latitude
-    pub latitude: Option<f64>,
+    /// Latitude in ddmm.mmmmm format
+    pub lat: Option<Cordinate>,
     /// Direction of latitude, N=north or S=south
     pub latitude_direction: Option<CardinalDirection>,
-    /// Longitude
-    pub longitude: Option<f64>,
+    /// Longitude in dddmm.mmmmm format
+    pub lon: Option<Cordinate>,
     /// Direction of longitude, E=east or W=west
     pub longitude_direction: Option<CardinalDirection>,
     /// Mode indicator for GPS
@@ -31,112 +38,115 @@ pub struct GNS {
     pub orthometric_height: Option<f64>,
     /// Geodial separation in meters. '-' means mean sea level in this case it will be presented as None
     pub geodial_separation: Option<f64>,
-    /// Age of differential GPS data in seconds
-    pub age_of_differential_gps_data: f64,
+    /// Age of differential GPS data in seconds, null when DGPS is not used
+    pub age_of_differential_gps_data: Option<f64>,
     /// Reference station ID, it could be null
-    pub reference_station_id: Option<String>,
+    pub reference_station_id: Option<InlineString<8>>,
 }
 
-impl Command<GNS> for GNS {
-    fn parse_command(&self, command: Vec<String>) -> Result<GNS, Error> {
-        let time_split: Vec<&str> = if command[0].contains('.') {
-            command[0].split('.').collect()
-        } else {
-            vec![&command[0], "0"]
-        };
-
-        let hour = time_split[0][..2].parse()?;
-        let minute = time_split[0][2..4].parse()?;
-        let second = time_split[0][4..6].parse()?;
-        let decimal_seconds = time_split[1].parse()?;
-        let time = Time {
-            hour,
-            minute,
-            second,
-            decimal_seconds,
-        };
-
-        let latitude = match command[1].parse() {
-            Ok(latitude) => Some(latitude),
-            Err(_) => None,
-        };
-
-        let latitude_direction = match command[2].parse::<char>() {
-            Ok(direction) => CardinalDirection::from_char(direction),
-            Err(_) => None,
-        };
-
-        let longitude = match command[3].parse() {
-            Ok(longitude) => Some(longitude),
-            Err(_) => None,
-        };
-
-        let longitude_direction = match command[4].parse::<char>() {
-            Ok(direction) => CardinalDirection::from_char(direction),
-            Err(_) => None,
-        };
-
-        let mode_indicator = command[5].chars();
-
-        let gps_mode_indicator = match mode_indicator.clone().nth(0) {
-            Some(mode) => ModeIndicator::from_char(mode),
-            None => None,
-        };
-
-        let glonass_mode_indicator = match mode_indicator.clone().nth(1) {
-            Some(mode) => ModeIndicator::from_char(mode),
-            None => None,
-        };
-
-        let galileo_mode_indicator = match mode_indicator.clone().nth(2) {
-            Some(mode) => ModeIndicator::from_char(mode),
-            None => None,
-        };
-
-        let beidou_mode_indicator = match mode_indicator.clone().nth(3) {
-            Some(mode) => ModeIndicator::from_char(mode),
-            None => None,
-        };
-
-        let qzss_mode_indicator = match mode_indicator.clone().nth(4) {
-            Some(mode) => ModeIndicator::from_char(mode),
-            None => None,
-        };
-
-        let number_of_satellites_in_use = command[6].parse()?;
-
-        let horizontal_dilution_of_precision = match command[7].parse::<f64>() {
-            Ok(dilution) => Some(dilution),
-            Err(_) => None,
-        };
-
-        let orthometric_height = match command[8].parse::<f64>() {
-            Ok(height) => Some(height),
-            Err(_) => None,
-        };
+impl GNS {
+    /// Latitude with its hemisphere, `None` without fix
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::CommandTypes};
+    /// let line = "$GNGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10.5,,,0.0,*67";
+    /// if let Ok(CommandTypes::GNS(gns)) = Parser::parse_line(line) {
+    ///     assert!((gns.latitude().unwrap().decimal_degrees() - 38.737335).abs() < 1e-6);
+    ///     assert!((gns.longitude().unwrap().decimal_degrees() + 9.140638).abs() < 1e-6);
+    /// } else {
+    ///     panic!("Expected GNS");
+    /// }
+    /// ```
+    pub fn latitude(&self) -> Option<Latitude> {
+        Some(Latitude {
+            cordinate: self.lat.clone()?,
+            direction: self.latitude_direction.clone()?,
+        })
+    }
 
-        let geodial_separation = match command[9].parse::<f64>() {
-            Ok(separation) => Some(separation),
-            Err(_) => None,
-        };
+    /// Longitude with its hemisphere, `None` without fix
+    pub fn longitude(&self) -> Option<Longitude> {
+        Some(Longitude {
+            cordinate: self.lon.clone()?,
+            direction: self.longitude_direction.clone()?,
+        })
+    }
+}
 
-        let age_of_differential_gps_data = command[10].parse()?;
-        let reference_station_id = match command[11].parse::<u16>() {
-            Ok(id) => Some(id.to_string()),
-            Err(_) => None,
+impl Command<GNS> for GNS {
+    fn parse_command(&self, command: &[&str]) -> Result<GNS, Error> {
+        if command.len() < 12 {
+            return Err(invalid_field_count(command));
+        }
+
+        let time = Time::from_str(command[0]).field(command, 0, "time")?;
+
+        let lat = Cordinate::from_str(command[1], 90)
+            .field(command, 1, "latitude")
+            .optional()?;
+
+        let latitude_direction = parse_code(
+            command,
+            2,
+            "latitude_direction",
+            CardinalDirection::from_str,
+        )
+        .optional()?;
+
+        let lon = Cordinate::from_str(command[3], 180)
+            .field(command, 3, "longitude")
+            .optional()?;
+
+        let longitude_direction = parse_code(
+            command,
+            4,
+            "longitude_direction",
+            CardinalDirection::from_str,
+        )
+        .optional()?;
+
+        // One character per system, receivers omit trailing systems they do not support,
+        // systems after QZSS are checked but not kept
+        let mut modes: [Option<ModeIndicator>; 5] = Default::default();
+        for (index, mode) in command[5].chars().enumerate() {
+            let mode = ModeIndicator::from_char(mode)
+                .ok_or_else(|| invalid_code(command, 5, "mode_indicator"))?;
+            if let Some(system_mode) = modes.get_mut(index) {
+                *system_mode = Some(mode);
+            }
+        }
+        let [gps, glonass, galileo, beidou, qzss] = modes;
+
+        let number_of_satellites_in_use = parse_field(command, 6, "number_of_satellites_in_use")?;
+
+        let horizontal_dilution_of_precision =
+            parse_field(command, 7, "horizontal_dilution_of_precision").optional()?;
+
+        let orthometric_height = parse_field(command, 8, "orthometric_height").optional()?;
+
+        let geodial_separation = parse_field(command, 9, "geodial_separation").optional()?;
+
+        let age_of_differential_gps_data =
+            parse_field(command, 10, "age_of_differential_gps_data").optional()?;
+        let reference_station_id = match command[11] {
+            "" => None,
+            id => {
+                parse_field::<u16>(command, 11, "reference_station_id")?;
+                Some(InlineString::try_from(id).field(command, 11, "reference_station_id")?)
+            }
         };
 
         Ok(GNS {
             time,
-            latitude,
+            lat,
             latitude_direction,
-            longitude,
+            lon,
             longitude_direction,
-            gps_mode_indicator,
-            glonass_mode_indicator,
-            galileo_mode_indicator,
-            beidou_mode_indicator,
-            qzss_mode_indicator,
+            gps_mode_indicator: gps,
+            glonass_mode_indicator: glonass,
+            galileo_mode_indicator: galileo,
+            beidou_mode_indicator: beidou,
+            qzss_mode_indicator: qzss,
             number_of_satellites_in_use,
             horizontal_dilution_of_precision,
             orthometric_height,
@@ -146,3 +156,36 @@ impl Command<GNS> for GNS {
         })
     }
 }
+
+impl Encode for GNS {
+    const SENTENCE_ID: &'static str = "GNS";
+
+    /// Mode indicators are written up to the first missing one
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.time(&self.time)?;
+        fields.optional_with(self.lat.as_ref(), |fields, lat| fields.cordinate(lat, 2))?;
+        fields.optional(self.latitude_direction.as_ref().map(|e| e.as_str()))?;
+        fields.optional_with(self.lon.as_ref(), |fields, lon| fields.cordinate(lon, 3))?;
+        fields.optional(self.longitude_direction.as_ref().map(|e| e.as_str()))?;
+        let mut modes = InlineString::<5>::new();
+        for mode in [
+            &self.gps_mode_indicator,
+            &self.glonass_mode_indicator,
+            &self.galileo_mode_indicator,
+            &self.beidou_mode_indicator,
+            &self.qzss_mode_indicator,
+        ]
+        .into_iter()
+        .map_while(Option::as_ref)
+        {
+            modes.push_str(mode.as_str()).map_err(|_| fmt::Error)?;
+        }
+        fields.field(modes)?;
+        fields.padded(self.number_of_satellites_in_use, 2)?;
+        fields.optional(self.horizontal_dilution_of_precision)?;
+        fields.optional(self.orthometric_height)?;
+        fields.optional(self.geodial_separation)?;
+        fields.optional(self.age_of_differential_gps_data)?;
+        fields.optional(self.reference_station_id)
+    }
+}
@@ -0,0 +1,120 @@
+use core::fmt;
+
+use crate::commands::gsa::GSA;
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, Command, Error, FieldContext, GRSMode,
+    OptionalField, Time,
+};
+
+/// GRS (GNSS Range Residuals)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GRS {
+    /// UTC Time of associated GGA fix
+    pub time: Time,
+    /// Computation method used for residuals
+    pub mode: GRSMode,
+    /// Range residuals in meters, in the same order as satellite IDs of GSA
+    pub residuals: [Option<f64>; 12],
+    /// GNSS System ID (NMEA 4.10 and later)
+    pub system_id: Option<u8>,
+    /// GNSS Signal ID (NMEA 4.10 and later), a hexadecimal digit
+    pub signal_id: Option<u8>,
+}
+
+impl Default for GRS {
+    fn default() -> Self {
+        Self {
+            time: Default::default(),
+            mode: GRSMode::UsedInGGA,
+            residuals: Default::default(),
+            system_id: Default::default(),
+            signal_id: Default::default(),
+        }
+    }
+}
+
+impl GRS {
+    /// Pair each residual with the satellite ID at the same position in given GSA of the same epoch
+    ///
+    /// Positions where either the satellite ID or the residual is empty are skipped
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::CommandTypes};
+    /// let gsa = Parser::parse_line("$GPGSA,A,3,05,12,25,,,,,,,,,,2.1,1.2,1.7*35");
+    /// let grs = Parser::parse_line("$GPGRS,082356.00,1,0.5,-12.8,,,,,,,,,,*57");
+    /// if let (Ok(CommandTypes::GSA(gsa)), Ok(CommandTypes::GRS(grs))) = (gsa, grs) {
+    ///     assert_eq!(grs.satellite_residuals(&gsa).collect::<Vec<_>>(), vec![(5, 0.5), (12, -12.8)]);
+    ///     assert_eq!(grs.outliers(&gsa, 10.0).collect::<Vec<_>>(), vec![12]);
+    /// } else {
+    ///     panic!("Expected GSA and GRS");
+    /// }
+    /// ```
+    pub fn satellite_residuals<'a>(&'a self, gsa: &'a GSA) -> impl Iterator<Item = (u8, f64)> + 'a {
+        gsa.satellites
+            .iter()
+            .zip(self.residuals.iter())
+            .filter_map(|(satellite, residual)| Some(((*satellite)?, (*residual)?)))
+    }
+
+    /// IDs of satellites whose absolute residual exceeds given threshold in meters
+    pub fn outliers<'a>(&'a self, gsa: &'a GSA, threshold: f64) -> impl Iterator<Item = u8> + 'a {
+        self.satellite_residuals(gsa)
+            .filter(move |(_, residual)| residual.abs() > threshold)
+            .map(|(satellite, _)| satellite)
+    }
+}
+
+impl Command<GRS> for GRS {
+    fn parse_command(&self, command: &[&str]) -> Result<GRS, Error> {
+        if command.len() != 14 && command.len() != 16 {
+            return Err(invalid_field_count(command));
+        }
+
+        let time = Time::from_str(command[0]).field(command, 0, "time")?;
+        let mode = parse_code(command, 1, "mode", GRSMode::from_str)?;
+        let mut residuals = [None; 12];
+        for (index, residual) in residuals.iter_mut().enumerate() {
+            *residual = parse_field(command, index + 2, "residual").optional()?;
+        }
+        let (system_id, signal_id) = if command.len() == 16 {
+            (
+                parse_field(command, 14, "system_id").optional()?,
+                parse_code(command, 15, "signal_id", |s| {
+                    u8::from_str_radix(s, 16).map_err(|_| s)
+                })
+                .optional()?,
+            )
+        } else {
+            (None, None)
+        };
+
+        Ok(GRS {
+            time,
+            mode,
+            residuals,
+            system_id,
+            signal_id,
+        })
+    }
+}
+
+impl Encode for GRS {
+    const SENTENCE_ID: &'static str = "GRS";
+
+    /// System and signal IDs are only written when either of them is set
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.time(&self.time)?;
+        fields.field(self.mode.as_str())?;
+        for residual in self.residuals {
+            fields.optional(residual)?;
+        }
+        if self.system_id.is_some() || self.signal_id.is_some() {
+            fields.optional(self.system_id)?;
+            fields.optional_with(self.signal_id, |fields, e| {
+                fields.field(format_args!("{:X}", e))
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,10 @@
-use crate::types::{Command, Error, GSAOperationMode, NavigationMode};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, Command, Error, GSAOperationMode, NavigationMode,
+    OptionalField,
+};
 
 /// GLL ( Geographic Position - Latitude/Longitude )
 #[derive(Debug, Clone, PartialEq)]
@@ -7,16 +13,16 @@ pub struct GSA {
     pub operation_mode: GSAOperationMode,
     /// Navigation mode
     pub navigation_mode: NavigationMode,
-    /// IDs of satellites used in navigation
-    pub satellites: Vec<Option<u8>>,
+    /// IDs of satellites used in navigation, one per channel
+    pub satellites: [Option<u8>; 12],
     /// Number of satellites used in navigation
     pub number_of_satellites: usize,
     /// PDOP ( Position Dilution of Precision )
-    pub pdop: f64,
+    pub pdop: Option<f64>,
     /// HDOP ( Horizontal Dilution of Precision )
-    pub hdop: f64,
+    pub hdop: Option<f64>,
     /// VDOP ( Vertical Dilution of Precision )
-    pub vdop: f64,
+    pub vdop: Option<f64>,
 }
 
 impl Default for GSA {
@@ -34,37 +40,21 @@ impl Default for GSA {
 }
 
 impl Command<GSA> for GSA {
-    fn parse_command(&self, command: Vec<String>) -> Result<GSA, crate::types::Error> {
-        let operation_mode = match GSAOperationMode::from_str(&command[0]) {
-            Ok(e) => e,
-            Err(_) => {
-                return Err(Error::ParseError(format!(
-                    "Invalid operation mode: {}",
-                    command[0]
-                )))
-            }
-        };
-        let navigation_mode = match NavigationMode::from_str(&command[1]) {
-            Ok(e) => e,
-            Err(_) => {
-                return Err(Error::ParseError(format!(
-                    "Invalid navigation mode: {}",
-                    command[1]
-                )))
-            }
-        };
-        let satellites: Vec<Option<u8>> = command[2..14]
-            .iter()
-            .map(|e| e.parse::<u8>().ok())
-            .collect();
-        let number_of_satellites = satellites
-            .iter()
-            .filter(|x| x.is_some())
-            .collect::<Vec<_>>()
-            .len();
-        let pdop = command[14].parse()?;
-        let hdop = command[15].parse()?;
-        let vdop = command[16].parse()?;
+    fn parse_command(&self, command: &[&str]) -> Result<GSA, Error> {
+        if command.len() < 17 {
+            return Err(invalid_field_count(command));
+        }
+
+        let operation_mode = parse_code(command, 0, "operation_mode", GSAOperationMode::from_str)?;
+        let navigation_mode = parse_code(command, 1, "navigation_mode", NavigationMode::from_str)?;
+        let mut satellites = [None; 12];
+        for (index, satellite) in satellites.iter_mut().enumerate() {
+            *satellite = parse_field(command, index + 2, "satellite_id").optional()?;
+        }
+        let number_of_satellites = satellites.iter().filter(|x| x.is_some()).count();
+        let pdop = parse_field(command, 14, "pdop").optional()?;
+        let hdop = parse_field(command, 15, "hdop").optional()?;
+        let vdop = parse_field(command, 16, "vdop").optional()?;
         Ok(GSA {
             operation_mode,
             navigation_mode,
@@ -76,3 +66,22 @@ impl Command<GSA> for GSA {
         })
     }
 }
+
+impl Encode for GSA {
+    const SENTENCE_ID: &'static str = "GSA";
+
+    /// Satellite IDs are written one per channel, `number_of_satellites` is derived from them
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.field(self.operation_mode.as_str())?;
+        fields.field(self.navigation_mode.as_str())?;
+        for satellite in self.satellites {
+            match satellite {
+                Some(id) => fields.padded(id, 2)?,
+                None => fields.empty()?,
+            }
+        }
+        fields.optional(self.pdop)?;
+        fields.optional(self.hdop)?;
+        fields.optional(self.vdop)
+    }
+}
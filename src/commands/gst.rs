@@ -0,0 +1,114 @@
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_field, Command, Error, FieldContext, OptionalField, Time,
+};
+
+/// Scale factor of a 2D normal distribution's standard deviation ellipse to its 95% confidence ellipse, `sqrt(-2 * ln(0.05))`
+const CONFIDENCE_95_SCALE: f64 = 2.447_746_830_680_816;
+
+/// GST (GNSS Pseudorange Error Statistics)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GST {
+    /// UTC Time
+    pub time: Time,
+    /// RMS value of the standard deviation of the ranges
+    pub rms: Option<f64>,
+    /// Standard deviation of semi-major axis of error ellipse, in meters
+    pub semi_major: Option<f64>,
+    /// Standard deviation of semi-minor axis of error ellipse, in meters
+    pub semi_minor: Option<f64>,
+    /// Orientation of semi-major axis of error ellipse, in degrees from true north
+    pub orientation: Option<f64>,
+    /// Standard deviation of latitude error, in meters
+    pub latitude_error: Option<f64>,
+    /// Standard deviation of longitude error, in meters
+    pub longitude_error: Option<f64>,
+    /// Standard deviation of altitude error, in meters
+    pub altitude_error: Option<f64>,
+}
+
+impl GST {
+    /// Horizontal covariance matrix of the error ellipse in square meters, ordered as `[[nn, ne], [en, ee]]` (north, east)
+    ///
+    /// Returns `None` when any of the ellipse fields is missing
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn covariance_matrix(&self) -> Option<[[f64; 2]; 2]> {
+        let semi_major = self.semi_major?;
+        let semi_minor = self.semi_minor?;
+        let orientation = self.orientation?.to_radians();
+
+        let (sin, cos) = orientation.sin_cos();
+        let major_variance = semi_major * semi_major;
+        let minor_variance = semi_minor * semi_minor;
+
+        let north_north = major_variance * cos * cos + minor_variance * sin * sin;
+        let east_east = major_variance * sin * sin + minor_variance * cos * cos;
+        let north_east = (major_variance - minor_variance) * sin * cos;
+
+        Some([[north_north, north_east], [north_east, east_east]])
+    }
+
+    /// Radius of a circle in meters enclosing the 95% confidence error ellipse
+    ///
+    /// The ellipse axes are scaled to 95% confidence and the semi-major axis is used as radius,
+    /// so the circle is conservative for elongated ellipses. Returns `None` when the semi-major axis is missing
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::CommandTypes};
+    /// let line = "$GPGST,082356.00,1.8,0.9,0.6,45.0,0.8,0.7,1.4*60";
+    /// if let Ok(CommandTypes::GST(gst)) = Parser::parse_line(line) {
+    ///     assert!((gst.confidence_radius_95().unwrap() - 2.203).abs() < 0.001);
+    /// } else {
+    ///     panic!("Expected GST");
+    /// }
+    /// ```
+    pub fn confidence_radius_95(&self) -> Option<f64> {
+        Some(self.semi_major? * CONFIDENCE_95_SCALE)
+    }
+}
+
+impl Command<GST> for GST {
+    fn parse_command(&self, command: &[&str]) -> Result<GST, Error> {
+        if command.len() != 8 {
+            return Err(invalid_field_count(command));
+        }
+
+        let time = Time::from_str(command[0]).field(command, 0, "time")?;
+        let rms = parse_field(command, 1, "rms").optional()?;
+        let semi_major = parse_field(command, 2, "semi_major").optional()?;
+        let semi_minor = parse_field(command, 3, "semi_minor").optional()?;
+        let orientation = parse_field(command, 4, "orientation").optional()?;
+        let latitude_error = parse_field(command, 5, "latitude_error").optional()?;
+        let longitude_error = parse_field(command, 6, "longitude_error").optional()?;
+        let altitude_error = parse_field(command, 7, "altitude_error").optional()?;
+
+        Ok(GST {
+            time,
+            rms,
+            semi_major,
+            semi_minor,
+            orientation,
+            latitude_error,
+            longitude_error,
+            altitude_error,
+        })
+    }
+}
+
+impl Encode for GST {
+    const SENTENCE_ID: &'static str = "GST";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.time(&self.time)?;
+        fields.optional(self.rms)?;
+        fields.optional(self.semi_major)?;
+        fields.optional(self.semi_minor)?;
+        fields.optional(self.orientation)?;
+        fields.optional(self.latitude_error)?;
+        fields.optional(self.longitude_error)?;
+        fields.optional(self.altitude_error)
+    }
+}
@@ -1,7 +1,12 @@
-use crate::types::{Command, Error};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{invalid_field_count, parse_code, parse_field, Command, Error, OptionalField};
 
 /// Sattelite struct
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Satellite {
     /// The satellite id
     pub id: Option<usize>,
@@ -14,6 +19,8 @@ pub struct Satellite {
 }
 
 /// GSV (Sattelite in View)
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct GSV {
     /// Total number of GSV Pages
@@ -32,73 +39,45 @@ pub struct GSVPage {
     /// Number of sattelites in this page
     pub number_of_known_satellites_in_view: usize,
     /// Sattelites in this page
-    pub satellites: Vec<Satellite>,
-    /// Signal ID
+    pub satellites: [Satellite; 4],
+    /// Signal ID (NMEA 4.10 and later), a hexadecimal digit
     pub signal_id: Option<usize>,
 }
 
 impl Command<GSVPage> for GSVPage {
-    fn parse_command(&self, command: Vec<String>) -> Result<GSVPage, Error> {
-        if command.len() < 4 {
-            return Err(Error::ParseError(format!(
-                "Invalid GSV command len: {}",
-                command.len()
-            )));
+    fn parse_command(&self, command: &[&str]) -> Result<GSVPage, Error> {
+        if command.len() < 3 {
+            return Err(invalid_field_count(command));
         }
 
-        let total_pages: usize = command[0].parse()?;
-        let page_id: usize = command[1].parse()?;
-        let number_of_known_satellites_in_view: usize = command[2].parse()?;
-
-        let mut satellites = Vec::new();
-
-        for i in 0..4 {
-            let idx = i * 4;
-
-            let id: Option<usize> = if command.len() > idx {
-                match command[idx].parse::<usize>() {
-                    Ok(e) => Some(e),
-                    Err(_) => None,
-                }
-            } else {
-                None
-            };
-
-            let elevation: Option<usize> = if command.len() > idx + 1 {
-                match command[idx + 1].parse::<usize>() {
-                    Ok(e) => Some(e),
-                    Err(_) => None,
-                }
-            } else {
-                None
-            };
+        let total_pages: usize = parse_field(command, 0, "total_pages")?;
+        let page_id: usize = parse_field(command, 1, "page_id")?;
+        let number_of_known_satellites_in_view: usize =
+            parse_field(command, 2, "number_of_known_satellites_in_view")?;
 
-            let azimuth: Option<usize> = if command.len() > idx + 2 {
-                match command[idx + 2].parse::<usize>() {
-                    Ok(e) => Some(e),
-                    Err(_) => None,
-                }
-            } else {
-                None
-            };
+        // Signal ID (NMEA 4.10 and later) follows the satellites of the page
+        let (satellite_end, signal_id) = match command[3..].len() % 4 {
+            1 => (
+                command.len() - 1,
+                parse_code(command, command.len() - 1, "signal_id", |s| {
+                    usize::from_str_radix(s, 16).map_err(|_| s)
+                })
+                .optional()?,
+            ),
+            _ => (command.len(), None),
+        };
+        let satellite_fields = &command[..satellite_end];
 
-            let snr: Option<usize> = if command.len() > idx + 3 {
-                match command[idx + 3].parse::<usize>() {
-                    Ok(e) => Some(e),
-                    Err(_) => None,
-                }
-            } else {
-                None
+        let mut satellites: [Satellite; 4] = Default::default();
+        for (position, satellite) in satellites.iter_mut().enumerate() {
+            let index = 3 + position * 4;
+            *satellite = Satellite {
+                id: parse_field(satellite_fields, index, "satellite_id").optional()?,
+                elevation: parse_field(satellite_fields, index + 1, "elevation").optional()?,
+                azimuth: parse_field(satellite_fields, index + 2, "azimuth").optional()?,
+                snr: parse_field(satellite_fields, index + 3, "snr").optional()?,
             };
-
-            satellites.push(Satellite {
-                id,
-                elevation,
-                azimuth,
-                snr,
-            });
         }
-        let signal_id = command[command.len() - 1].parse::<usize>().ok();
 
         Ok(GSVPage {
             total_pages,
@@ -109,3 +88,33 @@ impl Command<GSVPage> for GSVPage {
         })
     }
 }
+
+impl Encode for GSVPage {
+    const SENTENCE_ID: &'static str = "GSV";
+
+    /// Satellites are written up to the last one with any field set
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.field(self.total_pages)?;
+        fields.field(self.page_id)?;
+        fields.padded(self.number_of_known_satellites_in_view, 2)?;
+        let count = self
+            .satellites
+            .iter()
+            .rposition(|satellite| *satellite != Satellite::default())
+            .map_or(0, |position| position + 1);
+        for satellite in &self.satellites[..count] {
+            let mut padded = |value: Option<usize>, width| match value {
+                Some(value) => fields.padded(value, width),
+                None => fields.empty(),
+            };
+            padded(satellite.id, 2)?;
+            padded(satellite.elevation, 2)?;
+            padded(satellite.azimuth, 3)?;
+            padded(satellite.snr, 2)?;
+        }
+        match self.signal_id {
+            Some(signal_id) => fields.field(format_args!("{:X}", signal_id)),
+            None => Ok(()),
+        }
+    }
+}
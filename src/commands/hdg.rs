@@ -0,0 +1,100 @@
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, CardinalDirection, Command, Error, OptionalField,
+};
+
+/// HDG (Heading, Deviation & Variation)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HDG {
+    /// Magnetic sensor heading in degrees
+    pub heading: Option<f64>,
+    /// Magnetic deviation in degrees
+    pub deviation: Option<f64>,
+    /// Magnetic deviation E=East/W=West indicator
+    pub deviation_direction: Option<CardinalDirection>,
+    /// Magnetic variation in degrees
+    pub variation: Option<f64>,
+    /// Magnetic variation E=East/W=West indicator
+    pub variation_direction: Option<CardinalDirection>,
+}
+
+impl HDG {
+    /// True heading in degrees, sensor heading corrected by deviation and variation
+    ///
+    /// Easterly deviation and variation are added, westerly ones are subtracted.
+    /// Missing deviation or variation is treated as zero
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::CommandTypes};
+    /// let line = "$HCHDG,98.3,0.0,E,12.6,W*57";
+    /// if let Ok(CommandTypes::HDG(hdg)) = Parser::parse_line(line) {
+    ///     assert!((hdg.true_heading().unwrap() - 85.7).abs() < 1e-9);
+    /// } else {
+    ///     panic!("Expected HDG");
+    /// }
+    /// ```
+    pub fn true_heading(&self) -> Option<f64> {
+        let signed = |value: Option<f64>, direction: &Option<CardinalDirection>| match direction {
+            Some(CardinalDirection::West) => -value.unwrap_or_default(),
+            _ => value.unwrap_or_default(),
+        };
+        let heading = self.heading?
+            + signed(self.deviation, &self.deviation_direction)
+            + signed(self.variation, &self.variation_direction);
+        // `f64::rem_euclid` needs `std`
+        let heading = heading % 360.0;
+        Some(if heading < 0.0 {
+            heading + 360.0
+        } else {
+            heading
+        })
+    }
+}
+
+impl Command<HDG> for HDG {
+    fn parse_command(&self, command: &[&str]) -> Result<HDG, Error> {
+        if command.len() != 5 {
+            return Err(invalid_field_count(command));
+        }
+
+        let heading = parse_field(command, 0, "heading").optional()?;
+        let deviation = parse_field(command, 1, "deviation").optional()?;
+        let deviation_direction = parse_code(
+            command,
+            2,
+            "deviation_direction",
+            CardinalDirection::east_west,
+        )
+        .optional()?;
+        let variation = parse_field(command, 3, "variation").optional()?;
+        let variation_direction = parse_code(
+            command,
+            4,
+            "variation_direction",
+            CardinalDirection::east_west,
+        )
+        .optional()?;
+
+        Ok(HDG {
+            heading,
+            deviation,
+            deviation_direction,
+            variation,
+            variation_direction,
+        })
+    }
+}
+
+impl Encode for HDG {
+    const SENTENCE_ID: &'static str = "HDG";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional(self.heading)?;
+        fields.optional(self.deviation)?;
+        fields.optional(self.deviation_direction.as_ref().map(|e| e.as_str()))?;
+        fields.optional(self.variation)?;
+        fields.optional(self.variation_direction.as_ref().map(|e| e.as_str()))
+    }
+}
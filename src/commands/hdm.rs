@@ -0,0 +1,36 @@
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{invalid_code, invalid_field_count, parse_field, Command, Error, OptionalField};
+
+/// HDM (Heading, Magnetic)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HDM {
+    /// Heading in degrees from magnetic north
+    pub heading: Option<f64>,
+}
+
+impl Command<HDM> for HDM {
+    fn parse_command(&self, command: &[&str]) -> Result<HDM, Error> {
+        if command.len() != 2 {
+            return Err(invalid_field_count(command));
+        }
+
+        if command[1] != "M" {
+            return Err(invalid_code(command, 1, "heading_indicator"));
+        }
+
+        let heading = parse_field(command, 0, "heading").optional()?;
+
+        Ok(HDM { heading })
+    }
+}
+
+impl Encode for HDM {
+    const SENTENCE_ID: &'static str = "HDM";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional(self.heading)?;
+        fields.field('M')
+    }
+}
@@ -0,0 +1,36 @@
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{invalid_code, invalid_field_count, parse_field, Command, Error, OptionalField};
+
+/// HDT (Heading, True)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HDT {
+    /// Heading in degrees from true north
+    pub heading: Option<f64>,
+}
+
+impl Command<HDT> for HDT {
+    fn parse_command(&self, command: &[&str]) -> Result<HDT, Error> {
+        if command.len() != 2 {
+            return Err(invalid_field_count(command));
+        }
+
+        if command[1] != "T" {
+            return Err(invalid_code(command, 1, "heading_indicator"));
+        }
+
+        let heading = parse_field(command, 0, "heading").optional()?;
+
+        Ok(HDT { heading })
+    }
+}
+
+impl Encode for HDT {
+    const SENTENCE_ID: &'static str = "HDT";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional(self.heading)?;
+        fields.field('T')
+    }
+}
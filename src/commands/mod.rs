@@ -8,12 +8,32 @@ pub mod gga;
 pub mod gll;
 /// GNS (GNSS Fix Data)
 pub mod gns;
+/// GRS (GNSS Range Residuals)
+pub mod grs;
 /// GSA (GNSS Data)
 pub mod gsa;
+/// GST (GNSS Pseudorange Error Statistics)
+pub mod gst;
 /// GSV (GNSS Satellites in View)
 pub mod gsv;
+/// HDG (Heading, Deviation & Variation)
+pub mod hdg;
+/// HDM (Heading, Magnetic)
+pub mod hdm;
+/// HDT (Heading, True)
+pub mod hdt;
+/// RLM (Return Link Message)
+pub mod rlm;
 /// RMC (Recommended Minimum Specific GNSS Data)
 pub mod rmc;
+/// ROT (Rate Of Turn)
+pub mod rot;
+/// THS (True Heading and Status)
+pub mod ths;
+/// TXT (Text Transmission)
+pub mod txt;
+/// VLW (Dual Ground/Water Distance)
+pub mod vlw;
 /// VTG (Course Over Ground and Ground Speed)
 pub mod vtg;
 /// ZDA (Time & Date)
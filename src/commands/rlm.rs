@@ -0,0 +1,185 @@
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, Command, Error, ErrorKind, FieldContext, InlineString,
+    RLMMessageCode, Time,
+};
+
+/// Cospas-Sarsat 15 hex digit beacon ID (bits 26 to 85 of the beacon message)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BeaconId {
+    /// Raw 60 bit beacon ID
+    pub raw: u64,
+    /// Protocol flag, true for user and user-location protocols, false for location protocols
+    pub user_protocol: bool,
+    /// Country code (Maritime Identification Digits)
+    pub country_code: u16,
+    /// Protocol code, 3 bits for user protocols and 4 bits for location protocols
+    pub protocol_code: u8,
+    /// Remaining identification data of the protocol
+    pub identification: u64,
+}
+
+impl BeaconId {
+    /// Parse 15 hex digit beacon ID
+    pub fn from_hex(s: &str) -> Result<BeaconId, Error> {
+        if s.len() != 15 {
+            return Err(Error::new(ErrorKind::InvalidNumber));
+        }
+        let raw = match u64::from_str_radix(s, 16) {
+            Ok(e) => e,
+            Err(_) => return Err(Error::new(ErrorKind::InvalidNumber)),
+        };
+
+        let user_protocol = (raw >> 59) & 1 == 1;
+        let country_code = ((raw >> 49) & 0x3FF) as u16;
+        let (protocol_code, identification) = if user_protocol {
+            (((raw >> 46) & 0x7) as u8, raw & ((1 << 46) - 1))
+        } else {
+            (((raw >> 45) & 0xF) as u8, raw & ((1 << 45) - 1))
+        };
+
+        Ok(BeaconId {
+            raw,
+            user_protocol,
+            country_code,
+            protocol_code,
+            identification,
+        })
+    }
+}
+
+/// Decoded short return link message of an acknowledgement (type 1)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RLMAcknowledgement {
+    /// Type-1 acknowledgement, automatic acknowledgement of the alert
+    pub automatic: bool,
+    /// Type-2 acknowledgement, manual acknowledgement by a rescue coordination center
+    pub manual: bool,
+}
+
+/// RLM (Return Link Message)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RLM {
+    /// Beacon ID
+    pub beacon_id: BeaconId,
+    /// UTC Time of reception
+    pub time: Time,
+    /// Message code
+    pub message_code: RLMMessageCode,
+    /// Message body in hex, up to 40 digits of a long RLM
+    pub body: InlineString<48>,
+}
+
+impl Default for RLM {
+    fn default() -> Self {
+        Self {
+            beacon_id: Default::default(),
+            time: Default::default(),
+            message_code: RLMMessageCode::Reserved,
+            body: Default::default(),
+        }
+    }
+}
+
+/// RLM borrowing its message body from the parsed line, see [`Parser::parse_borrowed`](crate::parser::Parser::parse_borrowed)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RLMRef<'a> {
+    /// Beacon ID
+    pub beacon_id: BeaconId,
+    /// UTC Time of reception
+    pub time: Time,
+    /// Message code
+    pub message_code: RLMMessageCode,
+    /// Message body in hex
+    pub body: &'a str,
+}
+
+impl<'a> RLMRef<'a> {
+    /// Parse fields of a RLM sentence
+    pub(crate) fn parse(command: &[&'a str]) -> Result<RLMRef<'a>, Error> {
+        if command.len() != 4 {
+            return Err(invalid_field_count(command));
+        }
+
+        let beacon_id = BeaconId::from_hex(command[0]).field(command, 0, "beacon_id")?;
+        let time = Time::from_str(command[1]).field(command, 1, "time")?;
+        let message_code = parse_code(command, 2, "message_code", RLMMessageCode::from_str)?;
+
+        Ok(RLMRef {
+            beacon_id,
+            time,
+            message_code,
+            body: command[3],
+        })
+    }
+
+    /// Decode the short RLM body of an acknowledgement message, see [`RLM::acknowledgement`]
+    pub fn acknowledgement(&self) -> Option<RLMAcknowledgement> {
+        acknowledgement(&self.message_code, self.body)
+    }
+
+    /// Copy into an owned [`RLM`], fails when the body exceeds its capacity
+    pub fn into_owned(self) -> Result<RLM, Error> {
+        Ok(RLM {
+            beacon_id: self.beacon_id,
+            time: self.time,
+            message_code: self.message_code,
+            body: InlineString::try_from(self.body)
+                .map_err(|e| e.with_field(3, "body", self.body))?,
+        })
+    }
+}
+
+impl RLM {
+    /// Decode the short RLM body of an acknowledgement message
+    ///
+    /// Returns `None` when the message is not an acknowledgement or the body is not valid hex
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::{CommandTypes, RLMMessageCode}};
+    /// let line = "$GARLM,9C6AE2A50BFBFF0,083559.00,1,8000*08";
+    /// if let Ok(CommandTypes::RLM(rlm)) = Parser::parse_line(line) {
+    ///     assert_eq!(rlm.message_code, RLMMessageCode::Acknowledgement);
+    ///     assert_eq!(rlm.beacon_id.country_code, 227);
+    ///     let acknowledgement = rlm.acknowledgement().unwrap();
+    ///     assert!(acknowledgement.automatic);
+    ///     assert!(!acknowledgement.manual);
+    /// } else {
+    ///     panic!("Expected RLM");
+    /// }
+    /// ```
+    pub fn acknowledgement(&self) -> Option<RLMAcknowledgement> {
+        acknowledgement(&self.message_code, &self.body)
+    }
+}
+
+fn acknowledgement(message_code: &RLMMessageCode, body: &str) -> Option<RLMAcknowledgement> {
+    if *message_code != RLMMessageCode::Acknowledgement || body.is_empty() || body.len() > 16 {
+        return None;
+    }
+    let bits = body.len() as u32 * 4;
+    let body = u64::from_str_radix(body, 16).ok()?;
+    Some(RLMAcknowledgement {
+        automatic: (body >> (bits - 1)) & 1 == 1,
+        manual: (body >> (bits - 2)) & 1 == 1,
+    })
+}
+
+impl Command<RLM> for RLM {
+    fn parse_command(&self, command: &[&str]) -> Result<RLM, Error> {
+        RLMRef::parse(command)?.into_owned()
+    }
+}
+
+impl Encode for RLM {
+    const SENTENCE_ID: &'static str = "RLM";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.field(format_args!("{:015X}", self.beacon_id.raw))?;
+        fields.time(&self.time)?;
+        fields.field(self.message_code.as_str())?;
+        fields.field(self.body)
+    }
+}
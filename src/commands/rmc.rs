@@ -1,26 +1,34 @@
-use crate::types::{CardinalDirection, Command, Cordinate, Date, Error, Status, Time};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, CardinalDirection, Command, Cordinate, Date,
+    Error, FieldContext, Latitude, Longitude, OptionalField, Status, Time,
+};
 
 /// RMC ( Recommended Minimum Specific GPS Data )
+///
+/// Fields a receiver without fix leaves empty are `None`
 #[derive(Debug, Clone, PartialEq)]
 pub struct RMC {
     /// UTC Time in hhmmss.sss format
-    pub time: Time,
+    pub time: Option<Time>,
     /// Status
     pub status: Status,
     /// Latitude in ddmm.mmmm format
-    pub lat: Cordinate,
+    pub lat: Option<Cordinate>,
     /// N=North/S=South indicator
-    pub northing_indicator: CardinalDirection,
+    pub northing_indicator: Option<CardinalDirection>,
     /// Longitude in dddmm.mmmm format
-    pub lon: Cordinate,
+    pub lon: Option<Cordinate>,
     /// E=East/W=West indicator
-    pub easting_indicator: CardinalDirection,
+    pub easting_indicator: Option<CardinalDirection>,
     /// Speed over ground in knots
-    pub speed_over_ground: f32,
+    pub speed_over_ground: Option<f32>,
     /// Course over ground in degrees
     pub course_over_ground: Option<f32>,
     /// UTC Date
-    pub date: Date,
+    pub date: Option<Date>,
     /// Magnetic variation degrees (Easterly varient is positive)
     pub magnetic_variation: Option<f64>,
     /// Magnetic variation E=East/W=West indicator, it could be empty
@@ -33,9 +41,9 @@ impl Default for RMC {
             time: Default::default(),
             status: Status::Invalid,
             lat: Default::default(),
-            northing_indicator: CardinalDirection::North,
+            northing_indicator: Default::default(),
             lon: Default::default(),
-            easting_indicator: CardinalDirection::East,
+            easting_indicator: Default::default(),
             speed_over_ground: Default::default(),
             course_over_ground: Default::default(),
             date: Default::default(),
@@ -45,98 +53,65 @@ impl Default for RMC {
     }
 }
 
-impl Command<RMC> for RMC {
-    fn parse_command(&self, command: Vec<String>) -> Result<RMC, crate::types::Error> {
-        let time_split: Vec<&str> = if command[0].contains('.') {
-            command[0].split('.').collect()
-        } else {
-            vec![&command[0], "0"]
-        };
-
-        let hour = time_split[0][..2].parse::<u8>()?;
-        let minute = time_split[0][2..4].parse::<u8>()?;
-        let second = time_split[0][4..6].parse::<u8>()?;
-        let decimal_seconds = time_split[1].parse::<u8>()?;
-        let time = Time {
-            hour,
-            minute,
-            second,
-            decimal_seconds,
-        };
-
-        let status = match Status::from_str(&command[1]) {
-            Ok(e) => e,
-            Err(_) => return Err(Error::ParseError("Invalid status".to_string())),
-        };
-
-        let latitude_degree = command[2][..3].parse()?;
-        let latitude_minute = command[2][3..].parse()?;
-        let northing_indicator = match command[3].chars().next() {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid northing indicator".to_string())),
-        };
-
-        let northing_indicator = match CardinalDirection::from_char(northing_indicator) {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid northing indicator".to_string())),
-        };
-
-        let longitude_degree = command[4][..3].parse()?;
-        let longitude_minute = command[4][3..].parse()?;
-        let easting_indicator = match command[5].chars().next() {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-        };
-
-        let easting_indicator = match CardinalDirection::from_char(easting_indicator) {
-            Some(e) => e,
-            None => return Err(Error::ParseError("Invalid easting indicator".to_string())),
-        };
-
-        let lat = Cordinate {
-            degree: latitude_degree,
-            minute: latitude_minute,
-        };
-
-        let lon = Cordinate {
-            degree: longitude_degree,
-            minute: longitude_minute,
-        };
-
-        let speed_over_ground = command[6].parse()?;
-        let course_over_ground = command[7].parse::<f32>().ok();
-
-        let date_split = &command[8];
-        let day = date_split[..2].parse::<u8>()?;
-        let month = date_split[2..4].parse::<u8>()?;
-        let year = date_split[4..].parse::<usize>()?;
-
-        let date = Date { day, month, year };
+impl RMC {
+    /// Latitude with its hemisphere, `None` without fix
+    pub fn latitude(&self) -> Option<Latitude> {
+        Some(Latitude {
+            cordinate: self.lat.clone()?,
+            direction: self.northing_indicator.clone()?,
+        })
+    }
 
-        let magnetic_variation = match command[9].parse::<f64>() {
-            Ok(e) => Some(e),
-            Err(_) => None,
-        };
+    /// Longitude with its hemisphere, `None` without fix
+    pub fn longitude(&self) -> Option<Longitude> {
+        Some(Longitude {
+            cordinate: self.lon.clone()?,
+            direction: self.easting_indicator.clone()?,
+        })
+    }
+}
 
-        let magnetic_variation_indicator = match command[10].chars().next() {
-            Some(e) => {
-                if e == 'E' {
-                    'E'
-                } else if e == 'W' {
-                    'W'
-                } else if e == ' ' || e == '\0' {
-                    ' '
-                } else {
-                    return Err(Error::ParseError(
-                        "Invalid magnetic variation indicator".to_string(),
-                    ));
-                }
-            }
-            None => ' ',
-        };
+impl Command<RMC> for RMC {
+    fn parse_command(&self, command: &[&str]) -> Result<RMC, Error> {
+        if command.len() < 11 {
+            return Err(invalid_field_count(command));
+        }
 
-        let magnetic_variation_indicator =
-            CardinalDirection::from_char(magnetic_variation_indicator);
+        let time = Time::from_str(command[0])
+            .field(command, 0, "time")
+            .optional()?;
+        let status = parse_code(command, 1, "status", Status::from_str)?;
+        let lat = Cordinate::from_str(command[2], 90)
+            .field(command, 2, "latitude")
+            .optional()?;
+        let northing_indicator = parse_code(
+            command,
+            3,
+            "northing_indicator",
+            CardinalDirection::from_str,
+        )
+        .optional()?;
+        let lon = Cordinate::from_str(command[4], 180)
+            .field(command, 4, "longitude")
+            .optional()?;
+        let easting_indicator =
+            parse_code(command, 5, "easting_indicator", CardinalDirection::from_str).optional()?;
+
+        let speed_over_ground = parse_field(command, 6, "speed_over_ground").optional()?;
+        let course_over_ground = parse_field(command, 7, "course_over_ground").optional()?;
+
+        let date = Date::from_ddmmyy(command[8])
+            .field(command, 8, "date")
+            .optional()?;
+        let magnetic_variation = parse_field(command, 9, "magnetic_variation").optional()?;
+
+        let magnetic_variation_indicator = parse_code(
+            command,
+            10,
+            "magnetic_variation_indicator",
+            CardinalDirection::east_west,
+        )
+        .optional()?;
 
         Ok(RMC {
             time,
@@ -153,3 +128,25 @@ impl Command<RMC> for RMC {
         })
     }
 }
+
+impl Encode for RMC {
+    const SENTENCE_ID: &'static str = "RMC";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional_with(self.time.as_ref(), Fields::time)?;
+        fields.field(self.status.as_str())?;
+        fields.optional_with(self.lat.as_ref(), |fields, lat| fields.cordinate(lat, 2))?;
+        fields.optional(self.northing_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.optional_with(self.lon.as_ref(), |fields, lon| fields.cordinate(lon, 3))?;
+        fields.optional(self.easting_indicator.as_ref().map(|e| e.as_str()))?;
+        fields.optional(self.speed_over_ground)?;
+        fields.optional(self.course_over_ground)?;
+        fields.optional_with(self.date.as_ref(), Fields::date)?;
+        fields.optional(self.magnetic_variation)?;
+        fields.optional(
+            self.magnetic_variation_indicator
+                .as_ref()
+                .map(|e| e.as_str()),
+        )
+    }
+}
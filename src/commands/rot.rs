@@ -0,0 +1,49 @@
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, Command, Error, OptionalField, Status,
+};
+
+/// ROT (Rate Of Turn)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ROT {
+    /// Rate of turn in degrees per minute, negative values mean bow turns to port
+    pub rate_of_turn: Option<f64>,
+    /// Status
+    pub status: Status,
+}
+
+impl Default for ROT {
+    fn default() -> Self {
+        Self {
+            rate_of_turn: Default::default(),
+            status: Status::Invalid,
+        }
+    }
+}
+
+impl Command<ROT> for ROT {
+    fn parse_command(&self, command: &[&str]) -> Result<ROT, Error> {
+        if command.len() != 2 {
+            return Err(invalid_field_count(command));
+        }
+
+        let rate_of_turn = parse_field(command, 0, "rate_of_turn").optional()?;
+        let status = parse_code(command, 1, "status", Status::from_str)?;
+
+        Ok(ROT {
+            rate_of_turn,
+            status,
+        })
+    }
+}
+
+impl Encode for ROT {
+    const SENTENCE_ID: &'static str = "ROT";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional(self.rate_of_turn)?;
+        fields.field(self.status.as_str())
+    }
+}
@@ -0,0 +1,51 @@
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, Command, Error, HeadingModeIndicator,
+    OptionalField,
+};
+
+/// THS (True Heading and Status)
+#[derive(Debug, Clone, PartialEq)]
+pub struct THS {
+    /// Heading of vehicle in degrees from true north
+    pub heading: Option<f64>,
+    /// Mode indicator
+    pub mode_indicator: HeadingModeIndicator,
+}
+
+impl Default for THS {
+    fn default() -> Self {
+        Self {
+            heading: Default::default(),
+            mode_indicator: HeadingModeIndicator::NotValid,
+        }
+    }
+}
+
+impl Command<THS> for THS {
+    fn parse_command(&self, command: &[&str]) -> Result<THS, Error> {
+        if command.len() != 2 {
+            return Err(invalid_field_count(command));
+        }
+
+        let heading = parse_field(command, 0, "heading").optional()?;
+        let mode_indicator =
+            parse_code(command, 1, "mode_indicator", HeadingModeIndicator::from_str)?;
+
+        Ok(THS {
+            heading,
+            mode_indicator,
+        })
+    }
+}
+
+impl Encode for THS {
+    const SENTENCE_ID: &'static str = "THS";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional(self.heading)?;
+        fields.field(self.mode_indicator.as_str())
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_field, Command, Error, ErrorKind, FieldContext, InlineString,
+    TXTMessageType,
+};
+
+/// TXT (Text Transmission)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TXT {
+    /// Total number of sentences in this message, 01 to 99
+    pub total_sentences: u8,
+    /// Sentence number, 01 to 99
+    pub sentence_number: u8,
+    /// Text identifier, sentences of the same message share it
+    pub text_identifier: u8,
+    /// Text message, `^` escaped characters are decoded, at most 61 characters fit a standard sentence
+    pub text: InlineString<80>,
+}
+
+impl TXT {
+    /// Message type derived from the text identifier
+    pub fn message_type(&self) -> TXTMessageType {
+        TXTMessageType::from_identifier(self.text_identifier)
+    }
+}
+
+/// TXT borrowing its text from the parsed line, see [`Parser::parse_borrowed`](crate::parser::Parser::parse_borrowed)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TXTRef<'a> {
+    /// Total number of sentences in this message, 01 to 99
+    pub total_sentences: u8,
+    /// Sentence number, 01 to 99
+    pub sentence_number: u8,
+    /// Text identifier, sentences of the same message share it
+    pub text_identifier: u8,
+    /// Text message as received, `^` escaped characters are not decoded
+    pub text: &'a str,
+}
+
+impl<'a> TXTRef<'a> {
+    /// Parse fields of a TXT sentence, `data` holds the same fields joined by `,`
+    pub(crate) fn parse(command: &[&str], data: &'a str) -> Result<TXTRef<'a>, Error> {
+        let (total_sentences, sentence_number, text_identifier) = parse_header(command)?;
+        // Text may contain unescaped commas, keep them as part of the text
+        let text = data.splitn(4, ',').nth(3).unwrap_or_default();
+
+        Ok(TXTRef {
+            total_sentences,
+            sentence_number,
+            text_identifier,
+            text,
+        })
+    }
+
+    /// Message type derived from the text identifier
+    pub fn message_type(&self) -> TXTMessageType {
+        TXTMessageType::from_identifier(self.text_identifier)
+    }
+
+    /// Copy into an owned [`TXT`] decoding `^` escaped characters, fails when the text exceeds its capacity
+    pub fn into_owned(self) -> Result<TXT, Error> {
+        let mut text = InlineString::new();
+        unescape(&mut text, self.text).map_err(|e| e.with_field(3, "text", self.text))?;
+        Ok(TXT {
+            total_sentences: self.total_sentences,
+            sentence_number: self.sentence_number,
+            text_identifier: self.text_identifier,
+            text,
+        })
+    }
+}
+
+/// Reassembled multi-sentence TXT message
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TXTMessage {
+    /// Text identifier shared by all sentences of the message
+    pub text_identifier: u8,
+    /// Message type derived from the text identifier
+    pub message_type: TXTMessageType,
+    /// Concatenated text of all sentences
+    pub text: String,
+}
+
+/// Stitches multi-sentence TXT messages together by their text identifier
+///
+/// Sentences of a message must arrive in order, a message with a missing or repeated sentence is dropped
+/// # Examples
+/// ```
+/// use rust_nmea::{parser::Parser, types::{CommandTypes, TXTMessageType}, commands::txt::TXTAssembler};
+/// let mut assembler = TXTAssembler::default();
+/// let lines = [
+///     "$GPTXT,02,01,02,u-blox ag - www.u-blox.com*53",
+///     "$GPTXT,02,02,02,HW  UBX-G70xx   00070000 *77",
+/// ];
+/// let mut messages = Vec::new();
+/// for line in lines {
+///     if let Ok(CommandTypes::TXT(txt)) = Parser::parse_line(line) {
+///         messages.extend(assembler.push(txt));
+///     }
+/// }
+/// assert_eq!(messages.len(), 1);
+/// assert_eq!(messages[0].message_type, TXTMessageType::Notice);
+/// assert_eq!(messages[0].text, "u-blox ag - www.u-blox.comHW  UBX-G70xx   00070000 ");
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Default)]
+pub struct TXTAssembler {
+    pending: BTreeMap<u8, Vec<TXT>>,
+}
+
+#[cfg(feature = "alloc")]
+impl TXTAssembler {
+    /// Push a parsed TXT sentence, returns the message once its last sentence arrives
+    pub fn push(&mut self, txt: TXT) -> Option<TXTMessage> {
+        if txt.sentence_number == 1 {
+            self.pending.remove(&txt.text_identifier);
+        }
+
+        let parts = self.pending.entry(txt.text_identifier).or_default();
+        let expected = parts.len() + 1;
+        if txt.sentence_number as usize != expected
+            || parts
+                .first()
+                .is_some_and(|first| first.total_sentences != txt.total_sentences)
+        {
+            self.pending.remove(&txt.text_identifier);
+            return None;
+        }
+
+        let text_identifier = txt.text_identifier;
+        let complete = txt.sentence_number >= txt.total_sentences;
+        parts.push(txt);
+
+        if complete {
+            let parts = self.pending.remove(&text_identifier)?;
+            Some(TXTMessage {
+                text_identifier,
+                message_type: TXTMessageType::from_identifier(text_identifier),
+                text: parts.iter().map(|part| part.text.as_str()).collect(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Drop all partially received messages
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// Decode `^hh` escaped reserved characters and append them to `unescaped`
+fn unescape<const N: usize>(unescaped: &mut InlineString<N>, text: &str) -> Result<(), Error> {
+    let mut rest = text;
+    while let Some((text, escaped)) = rest.split_once('^') {
+        unescaped.push_str(text)?;
+        let hex = escaped.get(..2).unwrap_or(escaped);
+        let code = u8::from_str_radix(hex, 16).map_err(|_| Error::new(ErrorKind::InvalidCode))?;
+        unescaped.push(code as char)?;
+        rest = &escaped[hex.len()..];
+    }
+    unescaped.push_str(rest)
+}
+
+/// Text with reserved and non-printable characters `^hh` escaped
+struct Escaped<'a>(&'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '$'
+                | '*'
+                | ','
+                | '!'
+                | '\\'
+                | '^'
+                | '~'
+                | '\u{0}'..='\u{1F}'
+                | '\u{7F}'..='\u{FF}' => write!(f, "^{:02X}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse total sentences, sentence number and text identifier
+fn parse_header(command: &[&str]) -> Result<(u8, u8, u8), Error> {
+    if command.len() < 4 {
+        return Err(invalid_field_count(command));
+    }
+
+    Ok((
+        parse_field(command, 0, "total_sentences")?,
+        parse_field(command, 1, "sentence_number")?,
+        parse_field(command, 2, "text_identifier")?,
+    ))
+}
+
+impl Command<TXT> for TXT {
+    fn parse_command(&self, command: &[&str]) -> Result<TXT, Error> {
+        let (total_sentences, sentence_number, text_identifier) = parse_header(command)?;
+        // Text may contain unescaped commas, keep them as part of the text
+        let mut text = InlineString::new();
+        for (index, part) in command.iter().enumerate().skip(3) {
+            if index > 3 {
+                text.push(',').field(command, index, "text")?;
+            }
+            unescape(&mut text, part).field(command, index, "text")?;
+        }
+
+        Ok(TXT {
+            total_sentences,
+            sentence_number,
+            text_identifier,
+            text,
+        })
+    }
+}
+
+impl Encode for TXT {
+    const SENTENCE_ID: &'static str = "TXT";
+
+    /// Reserved and non-printable characters of the text are `^hh` escaped
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.padded(self.total_sentences, 2)?;
+        fields.padded(self.sentence_number, 2)?;
+        fields.padded(self.text_identifier, 2)?;
+        fields.field(Escaped(&self.text))
+    }
+}
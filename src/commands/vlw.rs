@@ -0,0 +1,287 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::commands::{gga::GGA, rmc::RMC};
+#[cfg(feature = "alloc")]
+use crate::encoder::encode;
+use crate::encoder::{Encode, Fields};
+#[cfg(feature = "alloc")]
+use crate::types::TalkerIds;
+use crate::types::{invalid_code, invalid_field_count, parse_field, Command, Error, OptionalField};
+#[cfg(feature = "std")]
+use crate::types::{GGAStatus, Status};
+
+/// Mean earth radius in nautical miles
+#[cfg(feature = "std")]
+const EARTH_RADIUS_NM: f64 = 3440.065;
+
+/// VLW (Dual Ground/Water Distance)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VLW {
+    /// Total cumulative water distance
+    pub total_water_distance: Option<f64>,
+    /// Total water distance unit, N (nautical miles)
+    pub total_water_distance_unit: Option<char>,
+    /// Water distance since reset
+    pub trip_water_distance: Option<f64>,
+    /// Trip water distance unit, N (nautical miles)
+    pub trip_water_distance_unit: Option<char>,
+    /// Total cumulative ground distance (NMEA 3.0 and later)
+    pub total_ground_distance: Option<f64>,
+    /// Total ground distance unit, N (nautical miles)
+    pub total_ground_distance_unit: Option<char>,
+    /// Ground distance since reset (NMEA 3.0 and later)
+    pub trip_ground_distance: Option<f64>,
+    /// Trip ground distance unit, N (nautical miles)
+    pub trip_ground_distance_unit: Option<char>,
+}
+
+impl VLW {
+    /// Build a checksummed `$TTVLW,...*HH` line for given talker
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_line(&self, talker_id: &TalkerIds) -> String {
+        encode(talker_id, self)
+    }
+}
+
+impl Encode for VLW {
+    const SENTENCE_ID: &'static str = "VLW";
+
+    /// Distances are written with at least two decimal places, ground distances only when either of them is set
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        let mut distance = |distance: Option<f64>, unit: Option<char>| {
+            match distance {
+                Some(distance) => fields.decimal(distance, 1, 2)?,
+                None => fields.empty()?,
+            }
+            fields.optional(unit)
+        };
+        distance(self.total_water_distance, self.total_water_distance_unit)?;
+        distance(self.trip_water_distance, self.trip_water_distance_unit)?;
+        if self.total_ground_distance.is_some()
+            || self.total_ground_distance_unit.is_some()
+            || self.trip_ground_distance.is_some()
+            || self.trip_ground_distance_unit.is_some()
+        {
+            distance(self.total_ground_distance, self.total_ground_distance_unit)?;
+            distance(self.trip_ground_distance, self.trip_ground_distance_unit)?;
+        }
+        Ok(())
+    }
+}
+
+impl Command<VLW> for VLW {
+    fn parse_command(&self, command: &[&str]) -> Result<VLW, Error> {
+        if command.len() != 4 && command.len() != 8 {
+            return Err(invalid_field_count(command));
+        }
+
+        // Distances are always in nautical miles
+        let unit = |index: usize, name: &'static str| match command[index] {
+            "" => Ok(None),
+            "N" => Ok(Some('N')),
+            _ => Err(invalid_code(command, index, name)),
+        };
+
+        let total_water_distance = parse_field(command, 0, "total_water_distance").optional()?;
+        let total_water_distance_unit = unit(1, "total_water_distance_unit")?;
+        let trip_water_distance = parse_field(command, 2, "trip_water_distance").optional()?;
+        let trip_water_distance_unit = unit(3, "trip_water_distance_unit")?;
+        let (
+            total_ground_distance,
+            total_ground_distance_unit,
+            trip_ground_distance,
+            trip_ground_distance_unit,
+        ) = if command.len() == 8 {
+            (
+                parse_field(command, 4, "total_ground_distance").optional()?,
+                unit(5, "total_ground_distance_unit")?,
+                parse_field(command, 6, "trip_ground_distance").optional()?,
+                unit(7, "trip_ground_distance_unit")?,
+            )
+        } else {
+            (None, None, None, None)
+        };
+
+        Ok(VLW {
+            total_water_distance,
+            total_water_distance_unit,
+            trip_water_distance,
+            trip_water_distance_unit,
+            total_ground_distance,
+            total_ground_distance_unit,
+            trip_ground_distance,
+            trip_ground_distance_unit,
+        })
+    }
+}
+
+/// Ground distance log accumulated from consecutive RMC/GGA positions
+///
+/// Positions without a valid fix are ignored. Distance is only added once the position moved at least
+/// [`DEFAULT_MIN_DISPLACEMENT`] away from the last counted position, so the jitter of a stationary
+/// receiver does not add up. Emitted VLW sentences carry the distances in both the water fields read by
+/// legacy plotters and the ground fields of NMEA 3.0 and later.
+/// # Examples
+/// ```
+/// use rust_nmea::{parser::Parser, types::{CommandTypes, TalkerIds}, commands::vlw::Odometer};
+/// let mut odometer = Odometer::default();
+/// for line in [
+///     "$GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A*57",
+///     "$GPRMC,083600.00,A,4718.11437,N,00833.91522,E,0.004,77.52,091202,,,A*57",
+/// ] {
+///     if let Ok(CommandTypes::RMC(rmc)) = Parser::parse_line(line) {
+///         odometer.push_rmc(&rmc);
+///     }
+/// }
+/// assert!((odometer.trip_distance() - 1.0).abs() < 0.01);
+/// let vlw = odometer.vlw();
+/// assert_eq!(vlw.total_ground_distance_unit, Some('N'));
+/// assert_eq!(vlw.total_water_distance, vlw.total_ground_distance);
+/// let line = odometer.to_line(&TalkerIds::GP);
+/// assert!(line.starts_with("$GPVLW,1.00,N,1.00,N,1.00,N,1.00,N*"));
+/// assert!(matches!(Parser::parse_line(line.trim_end()), Ok(CommandTypes::VLW(_))));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone)]
+pub struct Odometer {
+    total_distance: f64,
+    trip_distance: f64,
+    last_position: Option<(f64, f64)>,
+    min_displacement: f64,
+    water_distances: bool,
+}
+
+/// Default minimum displacement in nautical miles counted by an [`Odometer`], about 10 meters
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub const DEFAULT_MIN_DISPLACEMENT: f64 = 0.0054;
+
+#[cfg(feature = "std")]
+impl Default for Odometer {
+    fn default() -> Self {
+        Self {
+            total_distance: 0.0,
+            trip_distance: 0.0,
+            last_position: None,
+            min_displacement: DEFAULT_MIN_DISPLACEMENT,
+            water_distances: true,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Odometer {
+    /// Start an odometer with given total distance in nautical miles, e.g. restored from storage
+    pub fn with_total_distance(total_distance: f64) -> Odometer {
+        Odometer {
+            total_distance,
+            ..Default::default()
+        }
+    }
+
+    /// Count distance only after moving given nautical miles away from the last counted position,
+    /// 0 counts every position change
+    pub fn min_displacement(mut self, min_displacement: f64) -> Odometer {
+        self.min_displacement = min_displacement;
+        self
+    }
+
+    /// Also write distances to the water fields of VLW, enabled by default for plotters predating NMEA 3.0
+    pub fn water_distances(mut self, water_distances: bool) -> Odometer {
+        self.water_distances = water_distances;
+        self
+    }
+
+    /// Total ground distance in nautical miles
+    pub fn total_distance(&self) -> f64 {
+        self.total_distance
+    }
+
+    /// Ground distance since last trip reset in nautical miles
+    pub fn trip_distance(&self) -> f64 {
+        self.trip_distance
+    }
+
+    /// Reset trip distance, total distance is kept
+    pub fn reset_trip(&mut self) {
+        self.trip_distance = 0.0;
+    }
+
+    /// Accumulate distance to the position of a RMC, ignored when RMC status is invalid or the position is empty
+    pub fn push_rmc(&mut self, rmc: &RMC) {
+        if rmc.status == Status::Valid {
+            if let (Some(latitude), Some(longitude)) = (rmc.latitude(), rmc.longitude()) {
+                self.push_position(latitude.decimal_degrees(), longitude.decimal_degrees());
+            }
+        }
+    }
+
+    /// Accumulate distance to the position of a GGA, ignored when GGA has no fix or the position is empty
+    pub fn push_gga(&mut self, gga: &GGA) {
+        if gga.status != GGAStatus::Invalid {
+            if let (Some(latitude), Some(longitude)) = (gga.latitude(), gga.longitude()) {
+                self.push_position(latitude.decimal_degrees(), longitude.decimal_degrees());
+            }
+        }
+    }
+
+    /// Accumulate distance to given position in decimal degrees
+    pub fn push_position(&mut self, latitude: f64, longitude: f64) {
+        if let Some((last_latitude, last_longitude)) = self.last_position {
+            let distance = haversine(last_latitude, last_longitude, latitude, longitude);
+            if distance < self.min_displacement {
+                return;
+            }
+            self.total_distance += distance;
+            self.trip_distance += distance;
+        }
+        self.last_position = Some((latitude, longitude));
+    }
+
+    /// VLW carrying accumulated distances, water distances are empty when disabled with [`Odometer::water_distances`]
+    pub fn vlw(&self) -> VLW {
+        let water = |distance: f64| self.water_distances.then_some(distance);
+        let water_unit = self.water_distances.then_some('N');
+        VLW {
+            total_water_distance: water(self.total_distance),
+            total_water_distance_unit: water_unit,
+            trip_water_distance: water(self.trip_distance),
+            trip_water_distance_unit: water_unit,
+            total_ground_distance: Some(self.total_distance),
+            total_ground_distance_unit: Some('N'),
+            trip_ground_distance: Some(self.trip_distance),
+            trip_ground_distance_unit: Some('N'),
+        }
+    }
+
+    /// Checksummed VLW line for given talker, distances are rounded to two decimal places
+    pub fn to_line(&self, talker_id: &TalkerIds) -> String {
+        let round = |distance: Option<f64>| distance.map(|e| (e * 100.0).round() / 100.0);
+        let vlw = self.vlw();
+        VLW {
+            total_water_distance: round(vlw.total_water_distance),
+            trip_water_distance: round(vlw.trip_water_distance),
+            total_ground_distance: round(vlw.total_ground_distance),
+            trip_ground_distance: round(vlw.trip_ground_distance),
+            ..vlw
+        }
+        .to_line(talker_id)
+    }
+}
+
+/// Great circle distance in nautical miles between two positions in decimal degrees
+#[cfg(feature = "std")]
+fn haversine(latitude_a: f64, longitude_a: f64, latitude_b: f64, longitude_b: f64) -> f64 {
+    let latitude_delta = (latitude_b - latitude_a).to_radians();
+    let longitude_delta = (longitude_b - longitude_a).to_radians();
+    let a = (latitude_delta / 2.0).sin().powi(2)
+        + latitude_a.to_radians().cos()
+            * latitude_b.to_radians().cos()
+            * (longitude_delta / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_NM * a.sqrt().asin()
+}
@@ -1,4 +1,10 @@
-use crate::types::{Command, Error, ModeIndicator, VTGUnit};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, parse_code, parse_field, Command, Error, ModeIndicator, OptionalField,
+    VTGUnit,
+};
 
 //$GPVTG,81.47,T,,M,0.788,N,1.459,K,A*09
 
@@ -8,91 +14,87 @@ pub struct VTG {
     /// Course Over Ground True
     pub course_over_ground_true: Option<f32>,
     /// Course Over Ground True Unit
-    pub course_over_ground_unit: char,
+    pub course_over_ground_unit: Option<char>,
     /// Course Over Ground Magnetic
     pub course_over_ground_magnetic: Option<f32>,
     /// Course Over Ground Magnetic Unit
-    pub course_over_ground_magnetic_unit: char,
+    pub course_over_ground_magnetic_unit: Option<char>,
     /// Speed Over Ground First
     pub speed_over_ground_first: Option<f32>,
     /// Speed Over Ground First Unit
-    pub speed_over_ground_first_unit: VTGUnit,
+    pub speed_over_ground_first_unit: Option<VTGUnit>,
     /// Speed Over Ground Second
     pub speed_over_ground_second: Option<f32>,
     /// Speed Over Ground Second Unit
-    pub speed_over_ground_second_unit: VTGUnit,
-    /// Mode Indicator
-    pub mode_indicator: ModeIndicator,
+    pub speed_over_ground_second_unit: Option<VTGUnit>,
+    /// Mode Indicator (NMEA 2.3 and later)
+    pub mode_indicator: Option<ModeIndicator>,
 }
 
 impl Default for VTG {
     fn default() -> Self {
         Self {
             course_over_ground_true: Default::default(),
-            course_over_ground_unit: Default::default(),
+            course_over_ground_unit: Some('T'),
             course_over_ground_magnetic: Default::default(),
-            course_over_ground_magnetic_unit: Default::default(),
+            course_over_ground_magnetic_unit: Some('M'),
             speed_over_ground_first: Default::default(),
-            speed_over_ground_first_unit: VTGUnit::Knots,
+            speed_over_ground_first_unit: Some(VTGUnit::Knots),
             speed_over_ground_second: Default::default(),
-            speed_over_ground_second_unit: VTGUnit::Kmh,
-            mode_indicator: ModeIndicator::Autonomous,
+            speed_over_ground_second_unit: Some(VTGUnit::Kmh),
+            mode_indicator: Default::default(),
         }
     }
 }
 
+/// Unit field holding exactly `expected`, e.g. `T` of a true course
+fn unit(s: &str, expected: char) -> Result<char, &str> {
+    match s.parse::<char>() {
+        Ok(unit) if unit == expected => Ok(unit),
+        _ => Err(s),
+    }
+}
+
 impl Command<VTG> for VTG {
-    fn parse_command(&self, command: Vec<String>) -> Result<VTG, crate::types::Error> {
-        if command.len() != 9 {
-            Err(Error::ParseError(format!(
-                "Invalid VTG command length: {}",
-                command.join(" ")
-            )))
+    fn parse_command(&self, command: &[&str]) -> Result<VTG, Error> {
+        if command.len() != 8 && command.len() != 9 {
+            Err(invalid_field_count(command))
         } else {
-            let course_over_ground_true = command[0].parse::<f32>().ok();
-            let course_over_ground_unit = match command[1].chars().next() {
-                Some(e) => e,
-                None => {
-                    return Err(Error::ParseError(
-                        "Invalid course over ground unit".to_string(),
-                    ))
-                }
-            };
+            let course_over_ground_true =
+                parse_field(command, 0, "course_over_ground_true").optional()?;
+            let course_over_ground_unit =
+                parse_code(command, 1, "course_over_ground_unit", |s| unit(s, 'T')).optional()?;
 
-            let course_over_ground_magnetic = command[2].parse::<f32>().ok();
-            let course_over_ground_magnetic_unit = match command[3].chars().next() {
-                Some(e) => e,
-                None => {
-                    return Err(Error::ParseError(
-                        "Invalid course over ground magnetic unit".to_string(),
-                    ))
-                }
-            };
+            let course_over_ground_magnetic =
+                parse_field(command, 2, "course_over_ground_magnetic").optional()?;
+            let course_over_ground_magnetic_unit =
+                parse_code(command, 3, "course_over_ground_magnetic_unit", |s| {
+                    unit(s, 'M')
+                })
+                .optional()?;
 
-            let speed_over_ground_first = command[4].parse::<f32>().ok();
-            let speed_over_ground_first_unit = match VTGUnit::from_str(&command[5]) {
-                Ok(e) => e,
-                Err(_) => {
-                    return Err(Error::ParseError(
-                        "Invalid speed over ground first unit".to_string(),
-                    ))
-                }
-            };
+            let speed_over_ground_first =
+                parse_field(command, 4, "speed_over_ground_first").optional()?;
+            let speed_over_ground_first_unit = parse_code(
+                command,
+                5,
+                "speed_over_ground_first_unit",
+                VTGUnit::from_str,
+            )
+            .optional()?;
 
-            let speed_over_ground_second = command[6].parse::<f32>().ok();
-            let speed_over_ground_second_unit = match VTGUnit::from_str(&command[7]) {
-                Ok(e) => e,
-                Err(_) => {
-                    return Err(Error::ParseError(
-                        "Invalid speed over ground second unit".to_string(),
-                    ))
-                }
-            };
+            let speed_over_ground_second =
+                parse_field(command, 6, "speed_over_ground_second").optional()?;
+            let speed_over_ground_second_unit = parse_code(
+                command,
+                7,
+                "speed_over_ground_second_unit",
+                VTGUnit::from_str,
+            )
+            .optional()?;
 
-            let mode_indicator = match ModeIndicator::from_str(&command[8]) {
-                Ok(e) => e,
-                Err(_) => return Err(Error::ParseError("Invalid mode indicator".to_string())),
-            };
+            let mode_indicator =
+                parse_code(command, 8, "mode_indicator", ModeIndicator::from_str).optional()?;
 
             Ok(VTG {
                 course_over_ground_true,
@@ -108,3 +110,30 @@ impl Command<VTG> for VTG {
         }
     }
 }
+
+impl Encode for VTG {
+    const SENTENCE_ID: &'static str = "VTG";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.optional(self.course_over_ground_true)?;
+        fields.optional(self.course_over_ground_unit)?;
+        fields.optional(self.course_over_ground_magnetic)?;
+        fields.optional(self.course_over_ground_magnetic_unit)?;
+        fields.optional(self.speed_over_ground_first)?;
+        fields.optional(
+            self.speed_over_ground_first_unit
+                .as_ref()
+                .map(|e| e.as_str()),
+        )?;
+        fields.optional(self.speed_over_ground_second)?;
+        fields.optional(
+            self.speed_over_ground_second_unit
+                .as_ref()
+                .map(|e| e.as_str()),
+        )?;
+        match &self.mode_indicator {
+            Some(mode_indicator) => fields.field(mode_indicator.as_str()),
+            None => Ok(()),
+        }
+    }
+}
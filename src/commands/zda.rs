@@ -1,4 +1,10 @@
-use crate::types::{Command, Date, Error, Time};
+use core::fmt;
+
+use crate::encoder::{Encode, Fields};
+use crate::types::{
+    invalid_field_count, out_of_range, parse_field, Command, Date, Error, FieldContext, Time,
+    ZonedDateTime,
+};
 
 /// ZDA (Time and Date)
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -7,45 +13,84 @@ pub struct ZDA {
     pub time: Time,
     /// UTC Date
     pub date: Date,
-    /// Local zone hours, 00 to +-13 hrs
-    pub local_zone_hours: usize,
-    /// Local zone minutes, 00 to 59
-    pub local_zone_minutes: usize,
+    /// Local zone hours, -13 to +14 hrs
+    pub local_zone_hours: i8,
+    /// Local zone minutes, 00 to +-59, carries the same sign as the hours
+    pub local_zone_minutes: i8,
+}
+
+impl ZDA {
+    /// Local zone offset from UTC in minutes
+    pub fn local_zone_offset_minutes(&self) -> i16 {
+        self.local_zone_hours as i16 * 60 + self.local_zone_minutes as i16
+    }
+
+    /// Convert UTC time and date to local date and time using the local zone offset
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::{CommandTypes, Date}};
+    /// let line = "$GPZDA,023012.00,01,03,2024,-05,00*4A";
+    /// if let Ok(CommandTypes::ZDA(zda)) = Parser::parse_line(line) {
+    ///     let zoned = zda.zoned_date_time();
+    ///     assert_eq!(zoned.offset_minutes, -300);
+    ///     assert_eq!(zoned.date, Date { day: 29, month: 2, year: 2024 });
+    ///     assert_eq!(zoned.time.hour, 21);
+    ///     assert_eq!(zoned.unix_timestamp(), 1709260212);
+    /// } else {
+    ///     panic!("Expected ZDA");
+    /// }
+    /// ```
+    pub fn zoned_date_time(&self) -> ZonedDateTime {
+        ZonedDateTime::from_utc(&self.date, &self.time, self.local_zone_offset_minutes())
+    }
 }
 
 impl Command<ZDA> for ZDA {
-    fn parse_command(&self, command: Vec<String>) -> Result<ZDA, crate::types::Error> {
+    fn parse_command(&self, command: &[&str]) -> Result<ZDA, Error> {
         if command.len() != 6 {
-            Err(Error::ParseError(format!(
-                "Invalid ZDA command length: {}",
-                command.join(" ")
-            )))
+            Err(invalid_field_count(command))
         } else {
-            let time_split: Vec<&str> = if command[0].contains('.') {
-                command[0].split('.').collect()
-            } else {
-                vec![&command[0], "0"]
-            };
-
-            let hour = time_split[0][..2].parse::<u8>()?;
-            let minute = time_split[0][2..4].parse::<u8>()?;
-            let second = time_split[0][4..6].parse::<u8>()?;
-            let decimal_seconds = time_split[1].parse::<u8>()?;
-            let time = Time {
-                hour,
-                minute,
-                second,
-                decimal_seconds,
-            };
+            let time = Time::from_str(command[0]).field(command, 0, "time")?;
 
-            let day = command[1].parse::<u8>()?;
-            let month = command[2].parse::<u8>()?;
-            let year = command[3].parse::<usize>()?;
+            let day = parse_field::<u8>(command, 1, "day")?;
+            if !(1..=31).contains(&day) {
+                return Err(out_of_range(command, 1, "day"));
+            }
+            let month = parse_field::<u8>(command, 2, "month")?;
+            if !(1..=12).contains(&month) {
+                return Err(out_of_range(command, 2, "month"));
+            }
+            let year = parse_field::<usize>(command, 3, "year")?;
+            if year > 9999 {
+                return Err(out_of_range(command, 3, "year"));
+            }
 
             let date = Date { day, month, year };
 
-            let local_zone_hours = command[4].parse::<usize>()?;
-            let local_zone_minutes = command[5].parse::<usize>()?;
+            // Empty zone fields mean the receiver does not know the local zone, treat it as UTC
+            let local_zone_hours = if command[4].is_empty() {
+                0
+            } else {
+                parse_field::<i8>(command, 4, "local_zone_hours")?
+            };
+            if !(-13..=14).contains(&local_zone_hours) {
+                return Err(out_of_range(command, 4, "local_zone_hours"));
+            }
+            let local_zone_minutes = if command[5].is_empty() {
+                0
+            } else {
+                parse_field::<i8>(command, 5, "local_zone_minutes")?
+            };
+            if !(-59..=59).contains(&local_zone_minutes) {
+                return Err(out_of_range(command, 5, "local_zone_minutes"));
+            }
+
+            // Zone minutes follow the sign of zone hours, `-00` included
+            let local_zone_minutes = if command[4].starts_with('-') {
+                -local_zone_minutes.abs()
+            } else {
+                local_zone_minutes
+            };
 
             Ok(ZDA {
                 time,
@@ -56,3 +101,21 @@ impl Command<ZDA> for ZDA {
         }
     }
 }
+
+impl Encode for ZDA {
+    const SENTENCE_ID: &'static str = "ZDA";
+
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result {
+        fields.time(&self.time)?;
+        fields.padded(self.date.day, 2)?;
+        fields.padded(self.date.month, 2)?;
+        fields.padded(self.date.year, 4)?;
+        // The sign of the zone is carried by the hours, `-00` for zones west of UTC by less than an hour
+        if self.local_zone_hours < 0 || self.local_zone_minutes < 0 {
+            fields.field(format_args!("-{:02}", self.local_zone_hours.unsigned_abs()))?;
+        } else {
+            fields.padded(self.local_zone_hours, 2)?;
+        }
+        fields.padded(self.local_zone_minutes.unsigned_abs(), 2)
+    }
+}
@@ -0,0 +1,352 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use crate::parser::with_tokens;
+use crate::types::{Command, Cordinate, Date, Error, ErrorKind, InlineString, TalkerIds, Time};
+
+/// Sentence that can be written back as a checksummed NMEA line
+///
+/// Implement it for custom sentences to encode them with [`encode`] or [`encode_to`].
+pub trait Encode {
+    /// Sentence ID, e.g. `GGA`
+    const SENTENCE_ID: &'static str;
+
+    /// Write data fields in order, empty fields included
+    fn encode_fields(&self, fields: &mut Fields<'_>) -> fmt::Result;
+}
+
+/// Writer of the comma separated data fields of a sentence
+pub struct Fields<'a> {
+    writer: &'a mut dyn Write,
+}
+
+impl fmt::Debug for Fields<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fields").finish_non_exhaustive()
+    }
+}
+
+impl Fields<'_> {
+    /// Write a field as displayed
+    pub fn field<T: fmt::Display>(&mut self, value: T) -> fmt::Result {
+        write!(self.writer, ",{}", value)
+    }
+
+    /// Write an optional field, `None` is written as an empty field
+    pub fn optional<T: fmt::Display>(&mut self, value: Option<T>) -> fmt::Result {
+        match value {
+            Some(value) => self.field(value),
+            None => self.empty(),
+        }
+    }
+
+    /// Write an optional field with given writer, `None` is written as an empty field
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{encoder::Fields, types::Time};
+    /// # fn write(fields: &mut Fields<'_>, time: Option<Time>) -> core::fmt::Result {
+    /// fields.optional_with(time.as_ref(), Fields::time)?;
+    /// fields.optional_with(Some(5), |fields, id| fields.padded(id, 2))
+    /// # }
+    /// ```
+    pub fn optional_with<T, F>(&mut self, value: Option<T>, write: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Self, T) -> fmt::Result,
+    {
+        match value {
+            Some(value) => write(self, value),
+            None => self.empty(),
+        }
+    }
+
+    /// Write an empty field
+    pub fn empty(&mut self) -> fmt::Result {
+        self.writer.write_char(',')
+    }
+
+    /// Write a number zero-padded to given width, e.g. satellite IDs as `05`
+    pub fn padded<T: fmt::Display>(&mut self, value: T, width: usize) -> fmt::Result {
+        write!(self.writer, ",{:0width$}", value, width = width)
+    }
+
+    /// Write a decimal number with its integer part zero-padded to given digits and given decimal places
+    ///
+    /// Decimal places are added when needed to read back the same value.
+    pub fn decimal(
+        &mut self,
+        value: f64,
+        integer_digits: usize,
+        decimal_places: usize,
+    ) -> fmt::Result {
+        self.writer.write_char(',')?;
+        write_decimal(self.writer, value, integer_digits, decimal_places)
+    }
+
+    /// Write time in hhmmss.ss format, more decimal places are written when needed for the fraction
+    ///
+    /// [`Time::nanosecond`] must be below one second, debug builds panic otherwise.
+    pub fn time(&mut self, time: &Time) -> fmt::Result {
+        debug_assert!(
+            time.nanosecond < 1_000_000_000,
+            "nanosecond {} is not below one second",
+            time.nanosecond
+        );
+        write!(
+            self.writer,
+            ",{:02}{:02}{:02}.",
+            time.hour, time.minute, time.second
+        )?;
+        let mut fraction = time.nanosecond;
+        let mut places = 9;
+        while places > 2 && fraction.is_multiple_of(10) {
+            fraction /= 10;
+            places -= 1;
+        }
+        write!(self.writer, "{:0places$}", fraction)
+    }
+
+    /// Write date in ddmmyy format
+    pub fn date(&mut self, date: &Date) -> fmt::Result {
+        write!(
+            self.writer,
+            ",{:02}{:02}{:02}",
+            date.day, date.month, date.year
+        )
+    }
+
+    /// Write cordinate with given degree digits, ddmm.mmmmm for latitudes and dddmm.mmmmm for longitudes
+    pub fn cordinate(&mut self, cordinate: &Cordinate, degree_digits: usize) -> fmt::Result {
+        write!(self.writer, ",{:0degree_digits$}", cordinate.degree)?;
+        write_decimal(self.writer, cordinate.minute, 2, 5)
+    }
+}
+
+/// Write the shortest fixed point form with at least given decimal places reading back the same value
+fn write_decimal(
+    writer: &mut dyn Write,
+    value: f64,
+    integer_digits: usize,
+    decimal_places: usize,
+) -> fmt::Result {
+    for precision in decimal_places..=17 {
+        let width = integer_digits + precision + usize::from(precision > 0);
+        let mut fixed = InlineString::<64>::new();
+        if write!(fixed, "{:0width$.precision$}", value).is_err() {
+            break;
+        }
+        if fixed.parse::<f64>() == Ok(value) {
+            return writer.write_str(&fixed);
+        }
+    }
+    // Display gives the shortest form reading back the same value, pad its integer part
+    let mut shortest = InlineString::<64>::new();
+    if write!(shortest, "{}", value).is_err() {
+        return write!(writer, "{}", value);
+    }
+    let digits = shortest.trim_start_matches('-');
+    let integer = digits.find('.').unwrap_or(digits.len());
+    if digits.len() < shortest.len() {
+        writer.write_char('-')?;
+    }
+    for _ in integer..integer_digits {
+        writer.write_char('0')?;
+    }
+    writer.write_str(digits)
+}
+
+/// Writer computing the XOR checksum of everything written through it
+struct Checksum<'a, W: Write> {
+    writer: &'a mut W,
+    checksum: u8,
+}
+
+impl<W: Write> Write for Checksum<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.checksum = s
+            .bytes()
+            .fold(self.checksum, |checksum, byte| checksum ^ byte);
+        self.writer.write_str(s)
+    }
+}
+
+/// Write a `$TTSSS,...*HH\r\n` line of given sentence without allocating
+/// # Examples
+/// ```
+/// use rust_nmea::{encoder::encode_to, commands::hdt::HDT, types::{InlineString, TalkerIds}};
+/// let mut line = InlineString::<82>::new();
+/// encode_to(&TalkerIds::HE, &HDT { heading: Some(274.07) }, &mut line).unwrap();
+/// assert_eq!(line, "$HEHDT,274.07,T*19\r\n");
+/// ```
+pub fn encode_to<T: Encode, W: Write>(
+    talker_id: &TalkerIds,
+    sentence: &T,
+    writer: &mut W,
+) -> fmt::Result {
+    writer.write_char('$')?;
+    let mut body = Checksum {
+        writer,
+        checksum: 0,
+    };
+    body.write_str(talker_id.as_str())?;
+    body.write_str(T::SENTENCE_ID)?;
+    sentence.encode_fields(&mut Fields { writer: &mut body })?;
+    let checksum = body.checksum;
+    write!(writer, "*{:02X}\r\n", checksum)
+}
+
+/// Build a `$TTSSS,...*HH\r\n` line of given sentence, parsing the line gives the sentence back
+/// # Examples
+/// ```
+/// use rust_nmea::{encoder::encode, parser::Parser, types::{CommandTypes, TalkerIds}};
+/// let line = "$GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,*62";
+/// if let Ok(CommandTypes::GGA(gga)) = Parser::parse_line(line) {
+///     let encoded = encode(&TalkerIds::GP, &gga);
+///     assert!(encoded.ends_with("\r\n"));
+///     assert_eq!(Parser::parse_line(encoded.trim_end()), Ok(CommandTypes::GGA(gga)));
+/// } else {
+///     panic!("Expected GGA");
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode<T: Encode>(talker_id: &TalkerIds, sentence: &T) -> String {
+    let mut line = String::new();
+    // Writing to a `String` only fails when `Encode` implementations fail themselves
+    let _ = encode_to(talker_id, sentence, &mut line);
+    line
+}
+
+/// Sentence keeping the formatting of the line it was parsed from
+///
+/// Fields still holding their parsed value are written as received, e.g. `5.0` instead of `5`
+/// or `0.0` instead of an empty field, so re-encoding an unmodified sentence gives the parsed line
+/// back followed by `\r\n`. Modified fields are written in the standard format.
+/// # Examples
+/// ```
+/// use rust_nmea::{encoder::Formatted, commands::gga::GGA, types::InlineString};
+/// let line = "$GPGGA,161009.5,1122.2041,N,02339.3523,E,2,8,1.090,11.5,M,11.3,M,0.0,0120*75";
+/// let mut gga = Formatted::<GGA>::parse(line).unwrap();
+/// let mut encoded = InlineString::<82>::new();
+/// gga.encode_to(&mut encoded).unwrap();
+/// assert_eq!(encoded.trim_end(), line);
+/// gga.sentence.altitude = Some(12.25);
+/// let mut encoded = InlineString::<82>::new();
+/// gga.encode_to(&mut encoded).unwrap();
+/// assert_eq!(
+///     encoded,
+///     "$GPGGA,161009.5,1122.2041,N,02339.3523,E,2,8,1.090,12.25,M,11.3,M,0.0,0120*44\r\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formatted<T> {
+    /// Talker ID
+    pub talker_id: TalkerIds,
+    /// Parsed sentence
+    pub sentence: T,
+    format: RawFormat,
+}
+
+/// Formatting of a parsed line
+#[derive(Debug, Clone, PartialEq)]
+struct RawFormat {
+    /// Sentence start, `$` or `!`
+    start: char,
+    /// Data fields as received
+    data: InlineString<256>,
+    /// Data fields of the parsed sentence in the standard format
+    standard: InlineString<256>,
+    /// Checksum was received in lowercase hex
+    lowercase_checksum: bool,
+}
+
+impl<T: Encode> Formatted<T> {
+    /// Parse given line of sentence `T` and keep its formatting
+    pub fn parse(line: &str) -> Result<Formatted<T>, Error>
+    where
+        T: Command<T> + Default,
+    {
+        with_tokens(line, |tokens| {
+            if tokens.sentence_id != T::SENTENCE_ID {
+                return Err(Error::new(ErrorKind::InvalidCode).in_sentence(tokens.sentence_id));
+            }
+            let sentence = T::default()
+                .parse_command(tokens.fields)
+                .map_err(|e| e.in_sentence(tokens.sentence_id))?;
+            let standard = standard_fields(&sentence)
+                .map_err(|_| Error::new(ErrorKind::TooLong).in_sentence(tokens.sentence_id))?;
+            // The sentence starts at the last `$` or `!` before the checksum, see `tokenize`
+            let (sentence_text, checksum) = line.split_once('*').unwrap_or((line, ""));
+            let start = sentence_text
+                .rfind(['$', '!'])
+                .map_or('$', |index| char::from(sentence_text.as_bytes()[index]));
+
+            Ok(Formatted {
+                talker_id: tokens.talker_id.clone(),
+                format: RawFormat {
+                    start,
+                    data: InlineString::try_from(tokens.data)
+                        .map_err(|e| e.in_sentence(tokens.sentence_id))?,
+                    standard,
+                    lowercase_checksum: checksum.bytes().any(|byte| byte.is_ascii_lowercase()),
+                },
+                sentence,
+            })
+        })
+    }
+
+    /// Write the line, see [`encode_to`]
+    pub fn encode_to<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        let fields = standard_fields(&self.sentence)?;
+        writer.write_char(self.format.start)?;
+        let mut body = Checksum {
+            writer,
+            checksum: 0,
+        };
+        body.write_str(self.talker_id.as_str())?;
+        body.write_str(T::SENTENCE_ID)?;
+
+        let data = &self.format.data;
+        let standard = &self.format.standard;
+        if fields == *standard {
+            if !data.is_empty() || !fields.is_empty() {
+                body.write_char(',')?;
+            }
+            body.write_str(data)?;
+        } else if data.split(',').count() == standard.split(',').count() - 1 {
+            // Standard fields start with `,`, keep received fields whose value is unchanged
+            let received = data.split(',').zip(standard.split(',').skip(1));
+            for (field, (data, standard)) in fields.split(',').skip(1).zip(received) {
+                body.write_char(',')?;
+                body.write_str(if field == standard { data } else { field })?;
+            }
+        } else {
+            body.write_str(&fields)?;
+        }
+
+        let checksum = body.checksum;
+        if self.format.lowercase_checksum {
+            write!(writer, "*{:02x}\r\n", checksum)
+        } else {
+            write!(writer, "*{:02X}\r\n", checksum)
+        }
+    }
+
+    /// Build the line, see [`encode`]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_line(&self) -> String {
+        let mut line = String::new();
+        let _ = self.encode_to(&mut line);
+        line
+    }
+}
+
+/// Data fields of given sentence in the standard format, each preceded by `,`
+fn standard_fields<T: Encode>(sentence: &T) -> Result<InlineString<256>, fmt::Error> {
+    let mut fields = InlineString::new();
+    sentence.encode_fields(&mut Fields {
+        writer: &mut fields,
+    })?;
+    Ok(fields)
+}
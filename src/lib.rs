@@ -1,8 +1,9 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(test, deny(warnings))]
-#![doc(html_root_url = "https://docs.rs/rust_nmea/1.2.6")]
+#![doc(html_root_url = "https://docs.rs/rust_nmea/2.0.0")]
 //!# Rust NMEA
 //!NMEA (0183) parser and information library for Rust.
 //!
@@ -14,39 +15,68 @@
 //! let parsed = parser::Parser::parse_line(line);
 //! assert_eq!(parsed, Ok(
 //!    CommandTypes::GGA(GGA {
-//!        time: Time {
+//!        time: Some(Time {
 //!            hour: 16,
 //!            minute: 10,
 //!            second: 9,
-//!            decimal_seconds: 0,
-//!        },
-//!        lat: Cordinate {
-//!            degree: 112,
-//!            minute: 2.20418,
-//!        },
-//!        northing_indicator: CardinalDirection::North,
-//!        lon: Cordinate {
+//!            nanosecond: 0,
+//!        }),
+//!        lat: Some(Cordinate {
+//!            degree: 11,
+//!            minute: 22.20418,
+//!        }),
+//!        northing_indicator: Some(CardinalDirection::North),
+//!        lon: Some(Cordinate {
 //!            degree: 23,
 //!            minute: 39.35234,
-//!        },
-//!        easting_indicator: CardinalDirection::East,
+//!        }),
+//!        easting_indicator: Some(CardinalDirection::East),
 //!        status: GGAStatus::S2d3D,
-//!        number_of_satellites: 8,
-//!        horizontal_dilution_of_position: 1.09,
-//!        altitude: 11.5,
-//!        altitude_unit: "M".to_string(),
-//!        geoid_separation: 11.3,
-//!        geoid_separation_unit: "M".to_string(),
-//!        differential_age_of_position: 0.0,
-//!        differential_reference_station_id: 0,
+//!        number_of_satellites: Some(8),
+//!        horizontal_dilution_of_position: Some(1.09),
+//!        altitude: Some(11.5),
+//!        altitude_unit: Some("M".try_into().unwrap()),
+//!        geoid_separation: Some(11.3),
+//!        geoid_separation_unit: Some("M".try_into().unwrap()),
+//!        differential_age_of_position: None,
+//!        differential_reference_station_id: None,
 //!   })
 //! ));
 //! ```
+//!
+//!## `no_std`
+//!
+//! Parsing typed sentences does not allocate, text fields use fixed capacity [`InlineString`](types::InlineString)s.
+//! With the `alloc` feature, enabled by `std`, the data of [`ProprietarySentence`](types::ProprietarySentence)
+//! and [`UnknownSentence`](types::UnknownSentence) is a heap `String`. For parsing without any allocation use
+//! [`Parser::parse_borrowed`](parser::Parser::parse_borrowed) or disable default features.
+//! Disable default features to build without `std`, the `alloc` feature brings back
+//! [`Registry`](parser::Registry), [`StreamDecoder::push`](stream::StreamDecoder::push) and other allocating helpers.
+//!
 //! You can find more examples [here](https://github.com/ahmtcn123/Rust-NMEA/master/examples)
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Parallel parsing of large log files
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub mod batch;
+/// Tokio codec and async stream of sentences
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub mod codec;
 /// NMEA commands
 pub mod commands;
+/// Encode sentences into checksummed lines
+pub mod encoder;
 /// Parse
 pub mod parser;
+/// Line iterator over files, pipes and other readers
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod reader;
+/// Streaming decoder for raw serial input
+pub mod stream;
 /// Types
 pub mod types;
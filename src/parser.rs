@@ -1,20 +1,28 @@
-use crate::{
-    commands::gga::GGA,
-    types::{CommandTypes, Error, TalkerIds},
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::BTreeMap, format, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::types::{Command, CustomSentence};
+use crate::types::{
+    CommandTypes, CommandTypesRef, Error, ErrorKind, FramingError, InlineString, Sentence,
+    SentenceRef, TalkerIds,
 };
 
-/// Parser struct
-#[derive(Debug, Clone)]
-pub struct Parser {
-    /// CommandType
-    pub r#type: CommandTypes,
-    /// Commands to parse
-    pub commands: Vec<String>,
-    /// Talker ID
-    pub talker_id: TalkerIds,
-    type_start_collected: bool,
-    command_type_collected: bool,
-}
+/// Maximum number of data fields in a sentence, fields are split into a fixed size array
+pub const MAX_FIELDS: usize = 64;
+
+/// Fields of most sentences fit a smaller array, longer sentences are split again into [`MAX_FIELDS`]
+const SHORT_FIELDS: usize = 24;
+
+/// Parser of NMEA sentences
+///
+/// Lines are parsed in a single pass over their bytes which validates the checksum and splits the fields,
+/// followed by a dispatch on the sentence ID. Parsing never panics, malformed or truncated input is
+/// reported as an [`Error`].
+#[derive(Debug, Clone, Copy)]
+pub struct Parser;
 
 impl Parser {
     /// Parse given line
@@ -25,127 +33,360 @@ impl Parser {
     /// let parsed = parser::Parser::parse_line(line);
     /// assert_eq!(parsed, Ok(
     ///    CommandTypes::GGA(GGA {
-    ///        time: Time {
+    ///        time: Some(Time {
     ///            hour: 16,
     ///            minute: 10,
     ///            second: 9,
-    ///            decimal_seconds: 0,
-    ///        },
-    ///        lat: Cordinate {
-    ///            degree: 112,
-    ///            minute: 2.20418,
-    ///        },
-    ///        northing_indicator: CardinalDirection::North,
-    ///        lon: Cordinate {
+    ///            nanosecond: 0,
+    ///        }),
+    ///        lat: Some(Cordinate {
+    ///            degree: 11,
+    ///            minute: 22.20418,
+    ///        }),
+    ///        northing_indicator: Some(CardinalDirection::North),
+    ///        lon: Some(Cordinate {
     ///            degree: 23,
     ///            minute: 39.35234,
-    ///        },
-    ///        easting_indicator: CardinalDirection::East,
+    ///        }),
+    ///        easting_indicator: Some(CardinalDirection::East),
     ///        status: GGAStatus::S2d3D,
-    ///        number_of_satellites: 8,
-    ///        horizontal_dilution_of_position: 1.09,
-    ///        altitude: 11.5,
-    ///        altitude_unit: "M".to_string(),
-    ///        geoid_separation: 11.3,
-    ///        geoid_separation_unit: "M".to_string(),
-    ///        differential_age_of_position: 0.0,
-    ///        differential_reference_station_id: 0,
+    ///        number_of_satellites: Some(8),
+    ///        horizontal_dilution_of_position: Some(1.09),
+    ///        altitude: Some(11.5),
+    ///        altitude_unit: Some("M".try_into().unwrap()),
+    ///        geoid_separation: Some(11.3),
+    ///        geoid_separation_unit: Some("M".try_into().unwrap()),
+    ///        differential_age_of_position: None,
+    ///        differential_reference_station_id: None,
     ///   })
     /// ));
     pub fn parse_line(line: &str) -> Result<CommandTypes, Error> {
-        let mut parser = Parser {
-            r#type: CommandTypes::GGA(GGA::default()),
-            talker_id: TalkerIds::GP,
-            commands: Vec::new(),
-            type_start_collected: false,
-            command_type_collected: false,
-        };
-
-        let mut command = String::new();
-        let mut commands: Vec<String> = Vec::new();
-        let checksum = match line.split('*').last() {
-            Some(e) => e,
-            None => {
-                return Err(Error::ParseError("Invalid line".to_string()));
+        with_tokens(line, CommandTypes::parse)
+    }
+
+    /// Parse given line and keep its talker ID, sentence ID and field count
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::{CommandTypes, TalkerIds}};
+    /// let line = "$GLGSV,1,1,02,65,38,039,31,66,71,324,29,1*72";
+    /// let sentence = Parser::parse_sentence(line).unwrap();
+    /// assert_eq!(sentence.talker_id, TalkerIds::GL);
+    /// assert_eq!(sentence.sentence_id, "GSV");
+    /// assert_eq!(sentence.field_count, 12);
+    /// assert!(matches!(sentence.body, CommandTypes::GSV(_)));
+    /// ```
+    pub fn parse_sentence(line: &str) -> Result<Sentence, Error> {
+        Parser::parse_sentence_with(line, |_, _, _| None)
+    }
+
+    /// Parse given line from raw bytes, e.g. a buffer filled from a serial port
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::CommandTypes};
+    /// let sentence = Parser::parse_bytes(b"$GPZDA,023012.00,01,03,2024,-05,00*4A").unwrap();
+    /// assert!(matches!(sentence.body, CommandTypes::ZDA(_)));
+    /// ```
+    pub fn parse_bytes(line: &[u8]) -> Result<Sentence, Error> {
+        Parser::parse_sentence(line_from_bytes(line)?)
+    }
+
+    /// Parse given line into a [`SentenceRef`] borrowing its text fields from the line
+    ///
+    /// Nothing is allocated or copied besides numbers and short codes, [`SentenceRef::into_owned`]
+    /// converts the result into a [`Sentence`]. Parsers registered on a [`Registry`] are not used.
+    /// # Examples
+    /// ```
+    /// use rust_nmea::{parser::Parser, types::CommandTypesRef};
+    /// let line = String::from("$GPTXT,01,01,02,ANTSTATUS=OK*3B");
+    /// let sentence = Parser::parse_borrowed(&line).unwrap();
+    /// if let CommandTypesRef::TXT(txt) = &sentence.body {
+    ///     assert_eq!(txt.text, "ANTSTATUS=OK");
+    /// } else {
+    ///     panic!("Expected TXT");
+    /// }
+    /// let owned = sentence.into_owned().unwrap();
+    /// drop(line);
+    /// assert_eq!(owned.sentence_id, "TXT");
+    /// ```
+    pub fn parse_borrowed(line: &str) -> Result<SentenceRef<'_>, Error> {
+        with_tokens(line, |tokens| {
+            Ok(SentenceRef {
+                talker_id: tokens.talker_id.clone(),
+                sentence_id: tokens.sentence_id,
+                field_count: tokens.fields.len(),
+                body: CommandTypesRef::parse(tokens)?,
+            })
+        })
+    }
+
+    /// Parse given line, `custom` is asked first for a parser of the talker ID, sentence ID and fields
+    fn parse_sentence_with<F>(line: &str, custom: F) -> Result<Sentence, Error>
+    where
+        F: Fn(&TalkerIds, &str, &[&str]) -> Option<Result<CommandTypes, Error>>,
+    {
+        with_tokens(line, |tokens| {
+            let body = match custom(&tokens.talker_id, tokens.sentence_id, tokens.fields) {
+                Some(body) => body.map_err(|e| e.in_sentence(tokens.sentence_id))?,
+                None => CommandTypes::parse(tokens)?,
+            };
+            Ok(Sentence {
+                sentence_id: InlineString::try_from(tokens.sentence_id)?,
+                talker_id: tokens.talker_id.clone(),
+                field_count: tokens.fields.len(),
+                body,
+            })
+        })
+    }
+}
+
+/// Framing and checksum validated sentence split into fields, borrowing the line
+#[derive(Debug)]
+pub(crate) struct Tokens<'a, 'f> {
+    /// Talker ID, [`TalkerIds::Proprietary`] for `$P` sentences
+    pub(crate) talker_id: TalkerIds,
+    /// Sentence ID, the address without talker ID or without `P` for proprietary sentences
+    pub(crate) sentence_id: &'a str,
+    /// Data fields as received, separated by `,`
+    pub(crate) data: &'a str,
+    /// Data fields
+    pub(crate) fields: &'f [&'a str],
+}
+
+/// Tokenize given line and pass the tokens to `parse`
+///
+/// Fields are split into a small array first and only sentences not fitting it are split again into
+/// [`MAX_FIELDS`] fields, filling the large array costs as much as splitting a short sentence.
+pub(crate) fn with_tokens<'a, R, F>(line: &'a str, parse: F) -> Result<R, Error>
+where
+    F: FnOnce(&Tokens<'a, '_>) -> Result<R, Error>,
+{
+    let mut fields = [""; SHORT_FIELDS];
+    match tokenize(line, &mut fields) {
+        Err(e) if e.kind == ErrorKind::Framing(FramingError::TooManyFields) => {
+            let mut fields = [""; MAX_FIELDS];
+            parse(&tokenize(line, &mut fields)?)
+        }
+        tokens => parse(&tokens?),
+    }
+}
+
+/// Validate framing and checksum of given line and split it into `fields` in a single pass without allocating
+///
+/// Anything before the last `$` or `!` is ignored, the checksum follows the first `*`. Sentences with more
+/// fields than `fields` holds fail with [`FramingError::TooManyFields`].
+#[inline(always)]
+fn tokenize<'a, 'f>(line: &'a str, fields: &'f mut [&'a str]) -> Result<Tokens<'a, 'f>, Error> {
+    let mut start = 0;
+    let mut end = None;
+    let mut address_end = None;
+    let mut checksum_calculated = 0;
+    let mut field_count = 0;
+    let mut field_start = 0;
+    let mut overflow = false;
+
+    for (index, byte) in line.bytes().enumerate() {
+        match byte {
+            b'$' | b'!' => {
+                start = index + 1;
+                address_end = None;
+                checksum_calculated = 0;
+                field_count = 0;
+                overflow = false;
             }
-        };
-
-        //Parse hexa decimal checksum to u8
-        let checksum_u8: u8 = match u8::from_str_radix(checksum, 16) {
-            Ok(e) => e,
-            Err(_) => {
-                return Err(Error::ParseError(format!(
-                    "Invalid line, checksum is invalid \"{}\"",
-                    checksum
-                )));
+            b'*' => {
+                end = Some(index);
+                break;
             }
-        };
-
-        let command_clean = match line.split('$').last() {
-            Some(e) => e,
-            None => {
-                return Err(Error::ParseError("Invalid line".to_string()));
+            b',' => {
+                checksum_calculated ^= byte;
+                if address_end.is_none() {
+                    address_end = Some(index);
+                } else if field_count < fields.len() {
+                    fields[field_count] = &line[field_start..index];
+                    field_count += 1;
+                } else {
+                    overflow = true;
+                }
+                field_start = index + 1;
             }
-        };
-
-        let command_clean = command_clean.split('*').collect::<Vec<_>>()[0];
+            _ => checksum_calculated ^= byte,
+        }
+    }
 
-        //nmea checksum calculation
-        let mut checksum_calculated = 0;
-        for c in command_clean.chars() {
-            checksum_calculated ^= c as u8;
+    let end = match end {
+        Some(e) => e,
+        None => {
+            return Err(Error::new(ErrorKind::Framing(
+                FramingError::MissingChecksum,
+            )));
         }
+    };
 
-        if checksum_calculated != checksum_u8 {
-            return Err(Error::ChecksumError(checksum_u8, checksum_calculated));
+    //Parse hexa decimal checksum to u8
+    let checksum_u8: u8 = match u8::from_str_radix(&line[end + 1..], 16) {
+        Ok(e) => e,
+        Err(_) => {
+            return Err(Error::new(ErrorKind::Framing(
+                FramingError::InvalidChecksum,
+            )));
         }
+    };
 
-        for char in line.chars() {
-            command += &char.to_string();
-            if !parser.type_start_collected {
-                if command.len() == 3 {
-                    if TalkerIds::is_correct(&command) {
-                        parser.type_start_collected = true;
-                        parser.talker_id = TalkerIds::parse(&command);
-                        command = "".to_string();
-                    } else {
-                        return Err(Error::ParseError(format!(
-                            "Invalid command start \"{}\"",
-                            command
-                        )));
-                    }
-                }
-            } else if !parser.command_type_collected {
-                if command.len() == 4 && char == ',' {
-                    command = command.replace(',', "");
-                    match CommandTypes::from_str(&command) {
-                        Ok(command_type) => {
-                            parser.r#type = command_type;
-                            parser.command_type_collected = true;
-                            command = "".to_string();
-                        }
-                        Err(_) => {
-                            return Err(Error::UnknownCommand(command));
-                        }
-                    }
-                }
-            } else if char == ',' {
-                commands.push(command.replace(',', ""));
-                command = "".to_string();
-            } else if char == '*' {
-                commands.push(command.replace([',', '*'], ""));
-                command = "".to_string();
-                break;
+    let (address, data) = match address_end {
+        Some(address_end) => {
+            if field_count < fields.len() {
+                fields[field_count] = &line[field_start..end];
+                field_count += 1;
+            } else {
+                overflow = true;
             }
+            (&line[start..address_end], &line[address_end + 1..end])
         }
-        if !command.is_empty() {
-            commands.push(command);
+        None => (&line[start..end], ""),
+    };
+    let address = split_address(address);
+
+    if checksum_calculated != checksum_u8 {
+        let error = Error::new(ErrorKind::ChecksumMismatch {
+            received: checksum_u8,
+            computed: checksum_calculated,
+        });
+        return Err(match address {
+            Ok((_, sentence_id)) => error.in_sentence(sentence_id),
+            Err(_) => error,
+        });
+    }
+    let (talker_id, sentence_id) = address?;
+    if overflow {
+        return Err(
+            Error::new(ErrorKind::Framing(FramingError::TooManyFields)).in_sentence(sentence_id)
+        );
+    }
+
+    Ok(Tokens {
+        talker_id,
+        sentence_id,
+        data,
+        fields: &fields[..field_count],
+    })
+}
+
+/// Split address field into talker ID and sentence ID
+fn split_address(address: &str) -> Result<(TalkerIds, &str), Error> {
+    if let Some(proprietary) = address.strip_prefix('P') {
+        // Proprietary sentences carry a three letter manufacturer code after `P`
+        Ok((TalkerIds::Proprietary, proprietary))
+    } else {
+        match address.as_bytes() {
+            [first, second, _, ..]
+                if address.is_ascii()
+                    && [first, second]
+                        .iter()
+                        .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit()) =>
+            {
+                Ok((TalkerIds::from_code([*first, *second]), &address[2..]))
+            }
+            _ => Err(Error::new(ErrorKind::Framing(FramingError::InvalidAddress))),
         }
+    }
+}
+
+/// Line as text, sentences are ASCII so anything else is rejected
+pub(crate) fn line_from_bytes(line: &[u8]) -> Result<&str, Error> {
+    core::str::from_utf8(line)
+        .map_err(|_| Error::new(ErrorKind::Framing(FramingError::InvalidUtf8)))
+}
 
-        if parser.command_type_collected && parser.type_start_collected {
-            parser.r#type.parse_commands(commands)
-        } else {
-            Err(Error::ParseError("Invalid line".to_string()))
+#[cfg(feature = "alloc")]
+type CustomParser = Box<dyn Fn(&[&str]) -> Result<CustomSentence, Error> + Send + Sync>;
+
+/// Parsers of own sentences by sentence ID, dispatched before the built-in ones
+///
+/// Sentences are registered by their sentence ID, e.g. `DPT`, or by their full address for proprietary
+/// sentences, e.g. `PGRME`. Lines go through the same framing and checksum validation as [`Parser::parse_line`].
+/// # Examples
+/// ```
+/// use rust_nmea::{parser::Registry, types::{Command, CommandTypes, Error}};
+///
+/// #[derive(Debug, Clone, PartialEq, Default)]
+/// struct DPT {
+///     depth: f64,
+///     offset: f64,
+/// }
+///
+/// impl Command<DPT> for DPT {
+///     fn parse_command(&self, command: &[&str]) -> Result<DPT, Error> {
+///         Ok(DPT {
+///             depth: command[0].parse()?,
+///             offset: command[1].parse()?,
+///         })
+///     }
+/// }
+///
+/// let registry = Registry::new().register::<DPT>("DPT");
+/// if let Ok(CommandTypes::Custom(custom)) = registry.parse_line("$SDDPT,2.4,0.5*54") {
+///     assert_eq!(custom.downcast_ref::<DPT>(), Some(&DPT { depth: 2.4, offset: 0.5 }));
+/// } else {
+///     panic!("Expected custom sentence");
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Default)]
+pub struct Registry {
+    parsers: BTreeMap<String, CustomParser>,
+}
+
+#[cfg(feature = "alloc")]
+impl Registry {
+    /// Create an empty registry
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Register parser of `T` for given sentence ID, replaces previously registered parser of the same ID
+    pub fn register<T>(mut self, sentence_id: &str) -> Registry
+    where
+        T: Command<T> + Default + Clone + PartialEq + fmt::Debug + Send + Sync + 'static,
+    {
+        self.parsers.insert(
+            String::from(sentence_id),
+            Box::new(|command| T::default().parse_command(command).map(CustomSentence::new)),
+        );
+        self
+    }
+
+    /// Check if a parser is registered for given sentence ID
+    pub fn contains(&self, sentence_id: &str) -> bool {
+        self.parsers.contains_key(sentence_id)
+    }
+
+    /// Parse given line, registered sentences are returned as [`CommandTypes::Custom`]
+    pub fn parse_line(&self, line: &str) -> Result<CommandTypes, Error> {
+        self.parse_sentence(line).map(|sentence| sentence.body)
+    }
+
+    /// Parse given line and keep its talker ID, sentence ID and field count
+    pub fn parse_sentence(&self, line: &str) -> Result<Sentence, Error> {
+        Parser::parse_sentence_with(line, |talker_id, sentence_id, fields| {
+            self.get(talker_id, sentence_id)
+                .map(|custom_parser| custom_parser(fields).map(CommandTypes::Custom))
+        })
+    }
+
+    fn get(&self, talker_id: &TalkerIds, sentence_id: &str) -> Option<&CustomParser> {
+        match talker_id {
+            TalkerIds::Proprietary => self.parsers.get(&format!("P{}", sentence_id)),
+            _ => self.parsers.get(sentence_id),
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field("sentence_ids", &self.parsers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
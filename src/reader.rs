@@ -0,0 +1,295 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use crate::{
+    parser::{Parser, Registry},
+    types::{Error, ErrorKind, FramingError, Sentence},
+};
+
+/// Default of [`NmeaReader::max_line_length`], far above the 82 characters of a standard sentence
+pub const DEFAULT_MAX_LINE_LENGTH: usize = 4096;
+
+/// What [`NmeaReader`] does with a line that fails to parse
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RecoveryPolicy {
+    /// Skip the line and continue with the next one
+    Skip,
+    /// Yield the error and continue with the next line
+    #[default]
+    Yield,
+    /// Yield the error and end the iteration
+    Stop,
+}
+
+/// Sentence read by [`NmeaReader`] with its position in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadSentence {
+    /// Line number, starting from 1
+    pub line_number: usize,
+    /// Byte offset of the line start, in the decompressed stream for compressed sources
+    pub byte_offset: u64,
+    /// Parsed sentence
+    pub sentence: Sentence,
+}
+
+/// Error of [`NmeaReader`]
+#[derive(Debug)]
+pub enum ReadError {
+    /// Line failed to parse
+    Parse {
+        /// Line number, starting from 1
+        line_number: usize,
+        /// Byte offset of the line start
+        byte_offset: u64,
+        /// Parse error
+        error: Error,
+    },
+    /// Reading the source failed, iteration ends after it
+    Io(io::Error),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Parse {
+                line_number, error, ..
+            } => write!(f, "line {}: {}", line_number, error),
+            ReadError::Io(e) => write!(f, "read failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Parse { error, .. } => Some(error),
+            ReadError::Io(e) => Some(e),
+        }
+    }
+}
+
+/// Iterator of sentences over a [`BufRead`] source, one sentence per line
+///
+/// Blank lines are skipped, lines failing to parse are handled by the [`RecoveryPolicy`].
+/// Lines that are not UTF-8 or longer than [`NmeaReader::max_line_length`] fail with a framing error.
+/// # Examples
+/// ```
+/// use rust_nmea::{reader::{NmeaReader, ReadError}, types::CommandTypes};
+/// let log = "$GPZDA,023012.00,01,03,2024,-05,00*4A\r\n$GPZDA,broken*00\r\n\r\n$SDDPT,2.4,0.5*54\r\n";
+/// let items: Vec<_> = NmeaReader::new(log.as_bytes()).collect();
+/// assert_eq!(items.len(), 3);
+/// assert!(matches!(items[0].as_ref().unwrap().sentence.body, CommandTypes::ZDA(_)));
+/// assert!(matches!(items[1], Err(ReadError::Parse { line_number: 2, byte_offset: 39, .. })));
+/// assert_eq!(items[2].as_ref().unwrap().line_number, 4);
+/// ```
+#[derive(Debug)]
+pub struct NmeaReader<R> {
+    source: R,
+    policy: RecoveryPolicy,
+    registry: Option<Registry>,
+    line: Vec<u8>,
+    max_line_length: usize,
+    line_number: usize,
+    byte_offset: u64,
+    finished: bool,
+}
+
+impl<R: BufRead> NmeaReader<R> {
+    /// Create reader over given source with [`RecoveryPolicy::Yield`]
+    pub fn new(source: R) -> NmeaReader<R> {
+        NmeaReader {
+            source,
+            policy: RecoveryPolicy::default(),
+            registry: None,
+            line: Vec::new(),
+            max_line_length: DEFAULT_MAX_LINE_LENGTH,
+            line_number: 0,
+            byte_offset: 0,
+            finished: false,
+        }
+    }
+
+    /// Set recovery policy for lines failing to parse
+    pub fn policy(mut self, policy: RecoveryPolicy) -> NmeaReader<R> {
+        self.policy = policy;
+        self
+    }
+
+    /// Parse sentences with given registry, see [`Registry`]
+    pub fn registry(mut self, registry: Registry) -> NmeaReader<R> {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Set maximum line length in bytes without line ending, longer lines are skipped up to the next
+    /// line ending and fail with [`FramingError::TooLong`]
+    pub fn max_line_length(mut self, max_line_length: usize) -> NmeaReader<R> {
+        self.max_line_length = max_line_length;
+        self
+    }
+
+    /// Consume the reader and return its source
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+
+    /// Read the next line into `self.line`, returns the number of bytes consumed
+    fn read_line(&mut self) -> io::Result<usize> {
+        self.line.clear();
+        // Room for the line ending, anything beyond the limit is consumed without buffering it
+        let limit = (self.max_line_length as u64).saturating_add(2);
+        let read = (&mut self.source)
+            .take(limit)
+            .read_until(b'\n', &mut self.line)?;
+        if read as u64 == limit && !self.line.ends_with(b"\n") {
+            return Ok(read + self.source.skip_until(b'\n')?);
+        }
+        Ok(read)
+    }
+
+    /// Parse the line read last, `None` for blank lines
+    fn parse(&self) -> Result<Option<Sentence>, Error> {
+        let end = self
+            .line
+            .iter()
+            .rposition(|byte| !matches!(byte, b'\r' | b'\n'))
+            .map_or(0, |last| last + 1);
+        let line = &self.line[..end];
+        if line.len() > self.max_line_length {
+            return Err(Error::new(ErrorKind::Framing(FramingError::TooLong)));
+        }
+        let line = core::str::from_utf8(line)
+            .map_err(|_| Error::new(ErrorKind::Framing(FramingError::InvalidUtf8)))?;
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        match &self.registry {
+            Some(registry) => registry.parse_sentence(line).map(Some),
+            None => Parser::parse_sentence(line).map(Some),
+        }
+    }
+}
+
+impl NmeaReader<Box<dyn BufRead>> {
+    /// Open a log file, gzip and zstd compressed files are detected by their magic bytes
+    ///
+    /// Compressed files need the `gzip` or `zstd` feature, otherwise opening them fails
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<NmeaReader<Box<dyn BufRead>>> {
+        let mut file = BufReader::new(File::open(path)?);
+        let magic = file.fill_buf()?;
+        let source: Box<dyn BufRead> = if magic.starts_with(&[0x1f, 0x8b]) {
+            gzip_source(file)?
+        } else if magic.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            zstd_source(file)?
+        } else {
+            Box::new(file)
+        };
+        Ok(NmeaReader::new(source))
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gzip")))]
+impl<R: Read> NmeaReader<BufReader<flate2::read::MultiGzDecoder<R>>> {
+    /// Create reader over a gzip compressed source
+    pub fn from_gzip(source: R) -> NmeaReader<BufReader<flate2::read::MultiGzDecoder<R>>> {
+        NmeaReader::new(BufReader::new(flate2::read::MultiGzDecoder::new(source)))
+    }
+}
+
+#[cfg(feature = "zstd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zstd")))]
+impl<R: BufRead> NmeaReader<BufReader<zstd::stream::read::Decoder<'static, R>>> {
+    /// Create reader over a zstd compressed source
+    pub fn from_zstd(
+        source: R,
+    ) -> io::Result<NmeaReader<BufReader<zstd::stream::read::Decoder<'static, R>>>> {
+        Ok(NmeaReader::new(BufReader::new(
+            zstd::stream::read::Decoder::with_buffer(source)?,
+        )))
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn gzip_source<R: Read + 'static>(source: R) -> io::Result<Box<dyn BufRead>> {
+    Ok(Box::new(BufReader::new(flate2::read::MultiGzDecoder::new(
+        source,
+    ))))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip_source<R: Read + 'static>(_: R) -> io::Result<Box<dyn BufRead>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "gzip compressed log needs the `gzip` feature",
+    ))
+}
+
+#[cfg(feature = "zstd")]
+fn zstd_source<R: BufRead + 'static>(source: R) -> io::Result<Box<dyn BufRead>> {
+    Ok(Box::new(BufReader::new(
+        zstd::stream::read::Decoder::with_buffer(source)?,
+    )))
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_source<R: BufRead + 'static>(_: R) -> io::Result<Box<dyn BufRead>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "zstd compressed log needs the `zstd` feature",
+    ))
+}
+
+impl<R: BufRead> Iterator for NmeaReader<R> {
+    type Item = Result<ReadSentence, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let read = match self.read_line() {
+                Ok(0) => {
+                    self.finished = true;
+                    return None;
+                }
+                Ok(read) => read,
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(ReadError::Io(e)));
+                }
+            };
+
+            self.line_number += 1;
+            let line_number = self.line_number;
+            let byte_offset = self.byte_offset;
+            self.byte_offset += read as u64;
+
+            match self.parse() {
+                Ok(None) => continue,
+                Ok(Some(sentence)) => {
+                    return Some(Ok(ReadSentence {
+                        line_number,
+                        byte_offset,
+                        sentence,
+                    }))
+                }
+                Err(error) => {
+                    let error = ReadError::Parse {
+                        line_number,
+                        byte_offset,
+                        error,
+                    };
+                    match self.policy {
+                        RecoveryPolicy::Skip => continue,
+                        RecoveryPolicy::Yield => return Some(Err(error)),
+                        RecoveryPolicy::Stop => {
+                            self.finished = true;
+                            return Some(Err(error));
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::parser::{line_from_bytes, Registry};
+use crate::{
+    parser::Parser,
+    types::{Error, ErrorKind, FramingError, Sentence},
+};
+
+/// Default maximum sentence length in bytes, NMEA allows 82 but some receivers emit longer sentences
+pub const DEFAULT_MAX_SENTENCE_LENGTH: usize = 256;
+
+/// Byte oriented decoder that frames and parses sentences from raw serial input
+///
+/// Sentences start with `$` or `!` and end with `\r\n`, bare `\n` or bare `\r`.
+/// Bytes before a sentence start are dropped. A sentence start inside a sentence
+/// completes the buffered one when it already ends in a `*HH` checksum, otherwise
+/// the incomplete one is dropped and decoding starts over. Sentences longer than the
+/// maximum length are dropped until the next sentence start.
+///
+/// Sentences are buffered inline in `N` bytes, so the decoder does not allocate.
+/// # Examples
+/// ```
+/// use rust_nmea::{stream::StreamDecoder, types::CommandTypes};
+/// let mut decoder = StreamDecoder::new();
+/// assert_eq!(decoder.sentences(b"\0\0garbage$GPGGA,161009.00,1122.20418,N,02339.").count(), 0);
+/// let gga = decoder.sentences(b"35234,E,1,08,1.09,11.5,M,11.3,M,,*62\r\n$GPZDA,").last();
+/// assert!(matches!(gga.unwrap().unwrap().body, CommandTypes::GGA(_)));
+/// let zda = decoder.sentences(b"023012.00,01,03,2024,-05,00*4A\n").last();
+/// assert!(matches!(zda.unwrap().unwrap().body, CommandTypes::ZDA(_)));
+/// ```
+#[derive(Debug)]
+pub struct StreamDecoder<const N: usize = DEFAULT_MAX_SENTENCE_LENGTH> {
+    buffer: [u8; N],
+    len: usize,
+    max_length: usize,
+    in_sentence: bool,
+    #[cfg(feature = "alloc")]
+    registry: Option<Registry>,
+}
+
+impl<const N: usize> Default for StreamDecoder<N> {
+    fn default() -> Self {
+        Self {
+            buffer: [0; N],
+            len: 0,
+            max_length: N,
+            in_sentence: false,
+            #[cfg(feature = "alloc")]
+            registry: None,
+        }
+    }
+}
+
+impl StreamDecoder {
+    /// Create decoder with [`DEFAULT_MAX_SENTENCE_LENGTH`]
+    pub fn new() -> StreamDecoder {
+        StreamDecoder::default()
+    }
+
+    /// Create decoder with given maximum sentence length in bytes, line ending excluded
+    ///
+    /// The length is capped at [`DEFAULT_MAX_SENTENCE_LENGTH`], use `StreamDecoder::<N>::default()` for longer sentences
+    pub fn with_max_length(max_length: usize) -> StreamDecoder {
+        StreamDecoder {
+            max_length: max_length.min(DEFAULT_MAX_SENTENCE_LENGTH),
+            ..Default::default()
+        }
+    }
+}
+
+impl<const N: usize> StreamDecoder<N> {
+    /// Parse sentences with given registry, see [`Registry`]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn registry(mut self, registry: Registry) -> StreamDecoder<N> {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Feed a chunk of bytes, returns sentences completed by this chunk
+    /// # Examples
+    /// ```
+    /// use rust_nmea::stream::StreamDecoder;
+    /// let mut decoder = StreamDecoder::new();
+    /// assert!(decoder.push(b"$GPZDA,023012.00,01,03,").is_empty());
+    /// assert_eq!(decoder.push(b"2024,-05,00*4A\r\n$GPZDA,").len(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Result<Sentence, Error>> {
+        self.sentences(bytes).collect()
+    }
+
+    /// Feed a chunk of bytes, the returned iterator yields sentences completed by this chunk
+    ///
+    /// Bytes are only consumed as the iterator advances.
+    pub fn sentences<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> impl Iterator<Item = Result<Sentence, Error>> + 'a {
+        bytes.iter().filter_map(move |byte| self.push_byte(*byte))
+    }
+
+    /// Feed a single byte, returns a sentence once it is completed
+    pub fn push_byte(&mut self, byte: u8) -> Option<Result<Sentence, Error>> {
+        match byte {
+            b'$' | b'!' => {
+                // A sentence already ending in `*HH` only misses its line ending
+                let previous = if !self.in_sentence || self.len == 0 {
+                    None
+                } else if self.has_checksum() {
+                    Some(self.parse(&self.buffer[..self.len]))
+                } else {
+                    Some(Err(Error::new(ErrorKind::Framing(
+                        FramingError::Incomplete,
+                    ))))
+                };
+                // A decoder without buffer drops every sentence as too long
+                let Some(start) = self.buffer.first_mut() else {
+                    return Some(Err(Error::new(ErrorKind::Framing(FramingError::TooLong))));
+                };
+                *start = byte;
+                self.len = 1;
+                self.in_sentence = true;
+                previous
+            }
+            b'\r' | b'\n' => {
+                if self.in_sentence {
+                    self.in_sentence = false;
+                    let sentence = self.parse(&self.buffer[..self.len]);
+                    self.len = 0;
+                    Some(sentence)
+                } else {
+                    None
+                }
+            }
+            _ if self.in_sentence => {
+                if self.len >= self.max_length {
+                    self.in_sentence = false;
+                    self.len = 0;
+                    Some(Err(Error::new(ErrorKind::Framing(FramingError::TooLong))))
+                } else {
+                    self.buffer[self.len] = byte;
+                    self.len += 1;
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a buffered sentence missing its line ending, e.g. at the end of a stream
+    pub fn finish(&mut self) -> Option<Result<Sentence, Error>> {
+        self.push_byte(b'\n')
+    }
+
+    /// Drop buffered bytes of an incomplete sentence
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.in_sentence = false;
+    }
+
+    /// Buffered sentence ends in a complete `*HH` checksum
+    fn has_checksum(&self) -> bool {
+        matches!(
+            self.buffer[..self.len],
+            [.., b'*', high, low] if high.is_ascii_hexdigit() && low.is_ascii_hexdigit()
+        )
+    }
+
+    fn parse(&self, line: &[u8]) -> Result<Sentence, Error> {
+        #[cfg(feature = "alloc")]
+        if let Some(registry) = &self.registry {
+            return registry.parse_sentence(line_from_bytes(line)?);
+        }
+        Parser::parse_bytes(line)
+    }
+}
@@ -3,37 +3,850 @@ use crate::commands::gbs::GBS;
 use crate::commands::gga::GGA;
 use crate::commands::gll::GLL;
 use crate::commands::gns::GNS;
+use crate::commands::grs::GRS;
 use crate::commands::gsa::GSA;
+use crate::commands::gst::GST;
 use crate::commands::gsv::GSVPage;
+use crate::commands::hdg::HDG;
+use crate::commands::hdm::HDM;
+use crate::commands::hdt::HDT;
+use crate::commands::rlm::{RLMRef, RLM};
 use crate::commands::rmc::RMC;
+use crate::commands::rot::ROT;
+use crate::commands::ths::THS;
+use crate::commands::txt::{TXTRef, TXT};
+use crate::commands::vlw::VLW;
 use crate::commands::vtg::VTG;
+use crate::commands::zda::ZDA;
+use crate::parser::Tokens;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::any::Any;
+use core::fmt;
+use core::hash;
 use core::num;
+use core::ops;
+use core::time::Duration;
 
-/// Error struct
+/// Parse error with the sentence and field it occurred in
+///
+/// Errors are built without allocating. The raw field text is truncated to the capacity of [`ErrorField::raw`].
+/// # Examples
+/// ```
+/// use rust_nmea::{parser::Parser, types::ErrorKind};
+/// let error = Parser::parse_line("$GPZDA,023012.00,01,13,2024,-05,00*4B").unwrap_err();
+/// assert_eq!(error.kind, ErrorKind::OutOfRange);
+/// assert_eq!(error.sentence_id, "ZDA");
+/// let field = error.field.as_ref().unwrap();
+/// assert_eq!((field.index, field.name, field.raw.as_str()), (2, "month", "13"));
+/// assert_eq!(error.to_string(), "ZDA field 2 (month) \"13\": value out of range");
+/// ```
 #[derive(Debug, Clone, PartialEq)]
-pub enum Error {
-    /// Parser error
-    ParseError(String),
-    /// Unknown command
-    UnknownCommand(String),
-    /// Checksum error, expected, received
-    ChecksumError(u8, u8),
+pub struct Error {
+    /// What went wrong
+    pub kind: ErrorKind,
+    /// Sentence ID of the failing sentence, empty when the line could not be framed
+    pub sentence_id: InlineString<16>,
+    /// Failing field, `None` for errors of the whole sentence
+    pub field: Option<ErrorField>,
+}
+
+/// Field an [`Error`] occurred in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorField {
+    /// Index of the data field, the address field is not counted
+    pub index: usize,
+    /// Field name, e.g. `latitude`
+    pub name: &'static str,
+    /// Field text as received, truncated to 32 bytes
+    pub raw: InlineString<32>,
+}
+
+/// Kind of an [`Error`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Required field is empty or absent
+    MissingField,
+    /// Field is not a valid number
+    InvalidNumber,
+    /// Value is outside of its valid range
+    OutOfRange,
+    /// Field is not a known code of an enumeration, e.g. a status character
+    InvalidCode,
+    /// Text does not fit into the capacity of its field
+    TooLong,
+    /// Sentence has a field count its parser does not accept
+    InvalidFieldCount(usize),
+    /// Checksum of the sentence does not match the checksum it carries
+    ChecksumMismatch {
+        /// Checksum carried by the sentence
+        received: u8,
+        /// Checksum computed over the sentence
+        computed: u8,
+    },
+    /// Line is not a well formed sentence
+    Framing(FramingError),
+}
+
+/// Reason of an [`ErrorKind::Framing`] error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FramingError {
+    /// No `*` followed by a checksum
+    MissingChecksum,
+    /// Checksum is not a hexadecimal byte
+    InvalidChecksum,
+    /// Address field is not a known talker ID followed by a sentence ID, or an invalid proprietary address
+    InvalidAddress,
+    /// Sentence has more than [`MAX_FIELDS`](crate::parser::MAX_FIELDS) fields
+    TooManyFields,
+    /// Line is not valid UTF-8
+    InvalidUtf8,
+    /// Sentence exceeds the maximum length of a [`StreamDecoder`](crate::stream::StreamDecoder)
+    TooLong,
+    /// Next sentence started before the line ending
+    Incomplete,
+}
+
+impl Error {
+    /// Create an error of given kind without sentence or field context
+    pub fn new(kind: ErrorKind) -> Error {
+        Error {
+            kind,
+            sentence_id: InlineString::new(),
+            field: None,
+        }
+    }
+
+    /// Set the failing field, `raw` is truncated to the capacity of [`ErrorField::raw`]
+    pub fn with_field(mut self, index: usize, name: &'static str, raw: &str) -> Error {
+        let mut truncated = InlineString::new();
+        let _ = fmt::Write::write_str(&mut truncated, raw);
+        self.field = Some(ErrorField {
+            index,
+            name,
+            raw: truncated,
+        });
+        self
+    }
+
+    /// Set the sentence ID unless it is already set
+    pub(crate) fn in_sentence(mut self, sentence_id: &str) -> Error {
+        if self.sentence_id.is_empty() {
+            let _ = fmt::Write::write_str(&mut self.sentence_id, sentence_id);
+        }
+        self
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error::new(kind)
+    }
 }
 
 impl From<num::ParseIntError> for Error {
-    fn from(e: num::ParseIntError) -> Error {
-        Error::ParseError(format!("ParseIntError {}", e))
+    fn from(_: num::ParseIntError) -> Error {
+        Error::new(ErrorKind::InvalidNumber)
     }
 }
 
 impl From<num::ParseFloatError> for Error {
-    fn from(e: num::ParseFloatError) -> Error {
-        Error::ParseError(format!("ParseFloatError {}", e))
+    fn from(_: num::ParseFloatError) -> Error {
+        Error::new(ErrorKind::InvalidNumber)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.sentence_id.is_empty(), &self.field) {
+            (true, None) => {}
+            (false, None) => write!(f, "{}: ", self.sentence_id)?,
+            (true, Some(field)) => write!(
+                f,
+                "field {} ({}) \"{}\": ",
+                field.index, field.name, field.raw
+            )?,
+            (false, Some(field)) => write!(
+                f,
+                "{} field {} ({}) \"{}\": ",
+                self.sentence_id, field.index, field.name, field.raw
+            )?,
+        }
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingField => f.write_str("missing field"),
+            ErrorKind::InvalidNumber => f.write_str("invalid number"),
+            ErrorKind::OutOfRange => f.write_str("value out of range"),
+            ErrorKind::InvalidCode => f.write_str("invalid code"),
+            ErrorKind::TooLong => f.write_str("text too long"),
+            ErrorKind::InvalidFieldCount(count) => write!(f, "invalid field count {}", count),
+            ErrorKind::ChecksumMismatch { received, computed } => write!(
+                f,
+                "checksum mismatch, received {:02X}, computed {:02X}",
+                received, computed
+            ),
+            ErrorKind::Framing(e) => write!(f, "invalid framing, {}", e),
+        }
+    }
+}
+
+impl fmt::Display for FramingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FramingError::MissingChecksum => "missing checksum",
+            FramingError::InvalidChecksum => "invalid checksum",
+            FramingError::InvalidAddress => "invalid address",
+            FramingError::TooManyFields => "too many fields",
+            FramingError::InvalidUtf8 => "invalid UTF-8",
+            FramingError::TooLong => "sentence too long",
+            FramingError::Incomplete => "incomplete sentence",
+        })
+    }
+}
+
+/// Number type of a field, NMEA numbers are finite decimals
+pub(crate) trait Number: Sized {
+    /// Parse a plain decimal, `None` for anything else or a value out of range of the type
+    fn parse_decimal(s: &str) -> Option<Self>;
+}
+
+// Integers are parsed by hand, std parsing is a large share of parsing time for satellite tables
+macro_rules! integer_number {
+    ($($integer:ty),*) => {
+        $(impl Number for $integer {
+            fn parse_decimal(s: &str) -> Option<$integer> {
+                let (negative, digits) = match s.as_bytes() {
+                    [b'-', digits @ ..] if <$integer>::MIN != 0 => (true, digits),
+                    [b'+', digits @ ..] => (false, digits),
+                    digits => (false, digits),
+                };
+                if digits.is_empty() {
+                    return None;
+                }
+                digits.iter().try_fold(0 as $integer, |value, digit| {
+                    let digit = digit.wrapping_sub(b'0');
+                    if digit > 9 {
+                        return None;
+                    }
+                    let value = value.checked_mul(10)?;
+                    if negative {
+                        value.checked_sub(digit as $integer)
+                    } else {
+                        value.checked_add(digit as $integer)
+                    }
+                })
+            }
+        })*
+    };
+}
+
+integer_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+/// Sign, digits without the decimal point and number of fraction digits of a plain decimal
+fn decimal_parts(s: &str) -> Option<(bool, u64, usize)> {
+    let (negative, digits) = match s.as_bytes() {
+        [b'-', digits @ ..] => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+    let mut mantissa: u64 = 0;
+    let mut point = None;
+    for (index, &byte) in digits.iter().enumerate() {
+        match byte {
+            b'0'..=b'9' => {
+                mantissa = mantissa
+                    .checked_mul(10)?
+                    .checked_add(u64::from(byte - b'0'))?
+            }
+            b'.' if point.is_none() => point = Some(index),
+            _ => return None,
+        }
+    }
+    if digits.len() == usize::from(point.is_some()) {
+        return None;
+    }
+    Some((
+        negative,
+        mantissa,
+        point.map_or(0, |point| digits.len() - point - 1),
+    ))
+}
+
+// Decimals whose digits and power of ten are exact floats are divided directly, which rounds correctly,
+// anything else is left to std
+macro_rules! float_number {
+    ($($float:ty, $mantissa_bits:expr, $powers:expr);*) => {
+        $(impl Number for $float {
+            fn parse_decimal(s: &str) -> Option<$float> {
+                match decimal_parts(s) {
+                    Some((negative, mantissa, fraction_digits))
+                        if mantissa < 1 << $mantissa_bits && fraction_digits < $powers.len() =>
+                    {
+                        let value = mantissa as $float / $powers[fraction_digits];
+                        Some(if negative { -value } else { value })
+                    }
+                    _ => s
+                        .parse::<$float>()
+                        .ok()
+                        .filter(|value| value.is_finite() && is_decimal(s)),
+                }
+            }
+        })*
+    };
+}
+
+float_number!(
+    f32, 24, [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+    f64, 53, [
+        1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+        1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+    ]
+);
+
+/// Text is a plain decimal number, exponents, `inf` and `NaN` are not
+pub(crate) fn is_decimal(s: &str) -> bool {
+    s.bytes()
+        .all(|byte| byte.is_ascii_digit() || matches!(byte, b'.' | b'-' | b'+'))
+}
+
+/// Parse field `index` of a sentence, empty and absent fields are [`ErrorKind::MissingField`]
+///
+/// Numbers in exponent notation and non-finite values are [`ErrorKind::InvalidNumber`]
+pub(crate) fn parse_field<T: Number>(
+    command: &[&str],
+    index: usize,
+    name: &'static str,
+) -> Result<T, Error> {
+    let raw = command.get(index).copied().unwrap_or_default();
+    if raw.is_empty() {
+        return Err(Error::new(ErrorKind::MissingField).with_field(index, name, raw));
+    }
+    T::parse_decimal(raw)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidNumber).with_field(index, name, raw))
+}
+
+/// Parse code field `index` of a sentence with `from_str`, failures are [`ErrorKind::InvalidCode`]
+/// or [`ErrorKind::MissingField`] for empty and absent fields
+pub(crate) fn parse_code<T, F>(
+    command: &[&str],
+    index: usize,
+    name: &'static str,
+    from_str: F,
+) -> Result<T, Error>
+where
+    F: for<'s> FnOnce(&'s str) -> Result<T, &'s str>,
+{
+    let raw = command.get(index).copied().unwrap_or_default();
+    from_str(raw).map_err(|_| {
+        let kind = if raw.is_empty() {
+            ErrorKind::MissingField
+        } else {
+            ErrorKind::InvalidCode
+        };
+        Error::new(kind).with_field(index, name, raw)
+    })
+}
+
+/// Attach field context to errors of values parsed from a single field
+pub(crate) trait FieldContext<T> {
+    /// Set field `index` of `command` as the failing field
+    fn field(self, command: &[&str], index: usize, name: &'static str) -> Result<T, Error>;
+}
+
+impl<T> FieldContext<T> for Result<T, Error> {
+    fn field(self, command: &[&str], index: usize, name: &'static str) -> Result<T, Error> {
+        self.map_err(|e| {
+            let raw = command.get(index).copied().unwrap_or_default();
+            e.with_field(index, name, raw)
+        })
+    }
+}
+
+/// Read empty and absent fields as `None`, NMEA sends null fields for values it does not know
+pub(crate) trait OptionalField<T> {
+    /// Turn [`ErrorKind::MissingField`] into `None`, other errors are kept
+    fn optional(self) -> Result<Option<T>, Error>;
+}
+
+impl<T> OptionalField<T> for Result<T, Error> {
+    fn optional(self) -> Result<Option<T>, Error> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if matches!(e.kind, ErrorKind::MissingField) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Error of a field holding an invalid code, e.g. an unknown status character
+pub(crate) fn invalid_code(command: &[&str], index: usize, name: &'static str) -> Error {
+    let raw = command.get(index).copied().unwrap_or_default();
+    Error::new(ErrorKind::InvalidCode).with_field(index, name, raw)
+}
+
+/// Error of a field holding a value outside of its valid range
+pub(crate) fn out_of_range(command: &[&str], index: usize, name: &'static str) -> Error {
+    let raw = command.get(index).copied().unwrap_or_default();
+    Error::new(ErrorKind::OutOfRange).with_field(index, name, raw)
+}
+
+/// Error of a sentence with an unexpected field count
+pub(crate) fn invalid_field_count(command: &[&str]) -> Error {
+    Error::new(ErrorKind::InvalidFieldCount(command.len()))
+}
+
+/// String with a fixed capacity of `N` bytes stored inline, used for text fields so parsing does not allocate
+///
+/// Parsing a field longer than the capacity fails instead of truncating it.
+/// # Examples
+/// ```
+/// use rust_nmea::types::InlineString;
+/// let datum = InlineString::<8>::try_from("W84").unwrap();
+/// assert_eq!(datum, "W84");
+/// assert_eq!(datum.len(), 3);
+/// assert!(InlineString::<2>::try_from("W84").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct InlineString<const N: usize> {
+    bytes: [u8; N],
+    // Capacities are small, a narrow length keeps sentence types compact
+    len: u16,
+}
+
+impl<const N: usize> InlineString<N> {
+    /// Create an empty string
+    pub const fn new() -> InlineString<N> {
+        assert!(N <= u16::MAX as usize, "capacity must fit the u16 length");
+        InlineString {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Capacity in bytes
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Content as string slice
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+
+    /// Append given string, fails without changing the content when it does not fit
+    pub fn push_str(&mut self, s: &str) -> Result<(), Error> {
+        let start = self.len as usize;
+        let end = start + s.len();
+        if end > N {
+            return Err(Error::new(ErrorKind::TooLong));
+        }
+        self.bytes[start..end].copy_from_slice(s.as_bytes());
+        self.len = end as u16;
+        Ok(())
+    }
+
+    /// Append given char, fails without changing the content when it does not fit
+    pub fn push(&mut self, c: char) -> Result<(), Error> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Remove the content
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for InlineString<N> {
+    fn default() -> Self {
+        InlineString::new()
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for InlineString<N> {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut inline = InlineString::new();
+        inline.push_str(s)?;
+        Ok(inline)
+    }
+}
+
+impl<const N: usize> ops::Deref for InlineString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for InlineString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for InlineString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for InlineString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// Writes as much as fits, the rest is dropped at a char boundary and an error is returned
+impl<const N: usize> fmt::Write for InlineString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let start = self.len as usize;
+        let mut end = s.len().min(N - start);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.bytes[start..start + end].copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end as u16;
+        if end == s.len() {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+impl<const N: usize> PartialEq for InlineString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for InlineString<N> {}
+
+impl<const N: usize> PartialEq<str> for InlineString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for InlineString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<InlineString<N>> for str {
+    fn eq(&self, other: &InlineString<N>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<InlineString<N>> for &str {
+    fn eq(&self, other: &InlineString<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<const N: usize> hash::Hash for InlineString<N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// Sentence parser, built-in sentences implement it and own sentences can be registered on a [`Registry`]
+///
+/// `parse_command` receives the data fields between the sentence ID and the checksum,
+/// framing and checksum are validated by the parser before.
+///
+/// [`Registry`]: crate::parser::Registry
+pub trait Command<T> {
+    /// Parse data fields of a sentence
+    fn parse_command(&self, command: &[&str]) -> Result<T, Error>;
+}
+
+/// Object safe view of a parsed custom sentence
+#[cfg(feature = "alloc")]
+trait DynCommand: Any + fmt::Debug + Send + Sync {
+    fn clone_box(&self) -> Box<dyn DynCommand>;
+    fn eq_dyn(&self, other: &dyn DynCommand) -> bool;
+    fn as_any(&self) -> &dyn Any;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Any + fmt::Debug + Clone + PartialEq + Send + Sync> DynCommand for T {
+    fn clone_box(&self) -> Box<dyn DynCommand> {
+        Box::new(self.clone())
+    }
+
+    fn eq_dyn(&self, other: &dyn DynCommand) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<T>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Sentence parsed by a parser registered on a [`Registry`](crate::parser::Registry)
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct CustomSentence(Box<dyn DynCommand>);
+
+#[cfg(feature = "alloc")]
+impl CustomSentence {
+    pub(crate) fn new<T: Any + fmt::Debug + Clone + PartialEq + Send + Sync>(
+        command: T,
+    ) -> CustomSentence {
+        CustomSentence(Box::new(command))
+    }
+
+    /// Check if the sentence is of type `T`
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.as_any().is::<T>()
+    }
+
+    /// Get the sentence as `T`, `None` if it was parsed into another type
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.as_any().downcast_ref::<T>()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for CustomSentence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Clone for CustomSentence {
+    fn clone(&self) -> Self {
+        CustomSentence(self.0.clone_box())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for CustomSentence {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_dyn(other.0.as_ref())
+    }
+}
+
+/// Parsed sentence with its talker ID and sentence ID
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sentence {
+    /// Talker ID
+    pub talker_id: TalkerIds,
+    /// Sentence ID, e.g. `GGA`
+    pub sentence_id: InlineString<16>,
+    /// Number of data fields, without talker/sentence ID and checksum
+    pub field_count: usize,
+    /// Parsed sentence
+    pub body: CommandTypes,
+}
+
+/// Data fields of sentences kept as text, see [`ProprietarySentence`] and [`UnknownSentence`]
+///
+/// A `String` with the `alloc` feature. Without it data is stored inline and sentences with more than
+/// 256 bytes of data fail with [`ErrorKind::TooLong`].
+#[cfg(feature = "alloc")]
+pub type SentenceData = String;
+
+/// Data fields of sentences kept as text, see [`ProprietarySentence`] and [`UnknownSentence`]
+///
+/// A `String` with the `alloc` feature. Without it data is stored inline and sentences with more than
+/// 256 bytes of data fail with [`ErrorKind::TooLong`].
+#[cfg(not(feature = "alloc"))]
+pub type SentenceData = InlineString<256>;
+
+/// Copy data fields into [`SentenceData`]
+fn sentence_data(data: &str) -> Result<SentenceData, Error> {
+    #[cfg(feature = "alloc")]
+    return Ok(String::from(data));
+    #[cfg(not(feature = "alloc"))]
+    return InlineString::try_from(data);
+}
+
+/// Proprietary sentence, `$P` followed by a three letter manufacturer code
+/// # Examples
+/// ```
+/// use rust_nmea::{parser::Parser, types::{CommandTypes, TalkerIds}};
+/// let sentence = Parser::parse_sentence("$PGRME,15.0,M,45.0,M,25.0,M*1C").unwrap();
+/// assert_eq!(sentence.talker_id, TalkerIds::Proprietary);
+/// assert_eq!(sentence.sentence_id, "GRME");
+/// if let CommandTypes::Proprietary(proprietary) = sentence.body {
+///     assert_eq!(proprietary.manufacturer, "GRM");
+///     assert_eq!(proprietary.sentence_type, "E");
+///     assert_eq!(proprietary.fields().next(), Some("15.0"));
+/// } else {
+///     panic!("Expected proprietary sentence");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProprietarySentence {
+    /// Manufacturer code, e.g. `GRM` or `UBX`
+    pub manufacturer: InlineString<3>,
+    /// Sentence type following the manufacturer code, e.g. `E` of `$PGRME`, empty for `$PUBX`
+    pub sentence_type: InlineString<16>,
+    /// Data fields as received, separated by `,`
+    pub data: SentenceData,
+    /// Number of data fields
+    pub field_count: usize,
+}
+
+impl ProprietarySentence {
+    /// Data fields
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.data.split(',').take(self.field_count)
+    }
+}
+
+/// Proprietary sentence borrowing its text from the parsed line, see [`Parser::parse_borrowed`](crate::parser::Parser::parse_borrowed)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ProprietaryRef<'a> {
+    /// Manufacturer code, e.g. `GRM` or `UBX`
+    pub manufacturer: &'a str,
+    /// Sentence type following the manufacturer code, e.g. `E` of `$PGRME`, empty for `$PUBX`
+    pub sentence_type: &'a str,
+    /// Data fields as received, separated by `,`
+    pub data: &'a str,
+    /// Number of data fields
+    pub field_count: usize,
+}
+
+impl<'a> ProprietaryRef<'a> {
+    /// Split address field without leading `P` into manufacturer code and sentence type
+    pub(crate) fn from_address(address: &'a str) -> Result<ProprietaryRef<'a>, Error> {
+        if address.len() < 3
+            || !address
+                .bytes()
+                .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
+        {
+            return Err(Error::new(ErrorKind::Framing(FramingError::InvalidAddress)));
+        }
+        Ok(ProprietaryRef {
+            manufacturer: &address[..3],
+            sentence_type: &address[3..],
+            ..Default::default()
+        })
+    }
+
+    /// Data fields
+    pub fn fields(&self) -> impl Iterator<Item = &'a str> {
+        self.data.split(',').take(self.field_count)
+    }
+
+    /// Copy into an owned [`ProprietarySentence`], fails when a text field exceeds its capacity
+    pub fn into_owned(self) -> Result<ProprietarySentence, Error> {
+        Ok(ProprietarySentence {
+            manufacturer: InlineString::try_from(self.manufacturer)?,
+            sentence_type: InlineString::try_from(self.sentence_type)?,
+            data: sentence_data(self.data)?,
+            field_count: self.field_count,
+        })
+    }
+}
+
+/// Sentence with a sentence ID this crate does not parse, its fields are kept as text
+/// # Examples
+/// ```
+/// use rust_nmea::{parser::Parser, types::{CommandTypes, TalkerIds}};
+/// if let Ok(CommandTypes::Unknown(unknown)) = Parser::parse_line("$SDDPT,2.4,0.5*54") {
+///     assert_eq!(unknown.talker_id, TalkerIds::SD);
+///     assert_eq!(unknown.sentence_id, "DPT");
+///     assert_eq!(unknown.fields().collect::<Vec<_>>(), vec!["2.4", "0.5"]);
+/// } else {
+///     panic!("Expected unknown sentence");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownSentence {
+    /// Talker ID
+    pub talker_id: TalkerIds,
+    /// Sentence ID
+    pub sentence_id: InlineString<16>,
+    /// Data fields as received, separated by `,`
+    pub data: SentenceData,
+    /// Number of data fields
+    pub field_count: usize,
+}
+
+impl UnknownSentence {
+    /// Data fields
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.data.split(',').take(self.field_count)
+    }
+}
+
+/// Unknown sentence borrowing its text from the parsed line, see [`Parser::parse_borrowed`](crate::parser::Parser::parse_borrowed)
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownRef<'a> {
+    /// Talker ID
+    pub talker_id: TalkerIds,
+    /// Sentence ID
+    pub sentence_id: &'a str,
+    /// Data fields as received, separated by `,`
+    pub data: &'a str,
+    /// Number of data fields
+    pub field_count: usize,
+}
+
+impl<'a> UnknownRef<'a> {
+    /// Data fields
+    pub fn fields(&self) -> impl Iterator<Item = &'a str> {
+        self.data.split(',').take(self.field_count)
     }
+
+    /// Copy into an owned [`UnknownSentence`], fails when a text field exceeds its capacity
+    pub fn into_owned(self) -> Result<UnknownSentence, Error> {
+        Ok(UnknownSentence {
+            talker_id: self.talker_id,
+            sentence_id: InlineString::try_from(self.sentence_id)?,
+            data: sentence_data(self.data)?,
+            field_count: self.field_count,
+        })
+    }
+}
+
+/// Parsed sentence borrowing its text fields from the line, see [`Parser::parse_borrowed`](crate::parser::Parser::parse_borrowed)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentenceRef<'a> {
+    /// Talker ID
+    pub talker_id: TalkerIds,
+    /// Sentence ID, e.g. `GGA`
+    pub sentence_id: &'a str,
+    /// Number of data fields, without talker/sentence ID and checksum
+    pub field_count: usize,
+    /// Parsed sentence
+    pub body: CommandTypesRef<'a>,
 }
 
-pub(crate) trait Command<T> {
-    fn parse_command(&self, command: Vec<String>) -> Result<T, Error>;
+impl SentenceRef<'_> {
+    /// Copy into an owned [`Sentence`], fails when a text field exceeds the capacity of its owned counterpart
+    pub fn into_owned(self) -> Result<Sentence, Error> {
+        Ok(Sentence {
+            talker_id: self.talker_id,
+            sentence_id: InlineString::try_from(self.sentence_id)?,
+            field_count: self.field_count,
+            body: self.body.into_owned()?,
+        })
+    }
 }
 
 /// Command Types
@@ -57,63 +870,203 @@ pub enum CommandTypes {
     GBS(GBS),
     /// GNS ( GNSS Fix Data )
     GNS(GNS),
+    /// ZDA ( Time & Date )
+    ZDA(ZDA),
+    /// GST ( GNSS Pseudorange Error Statistics )
+    GST(GST),
+    /// GRS ( GNSS Range Residuals )
+    GRS(GRS),
+    /// TXT ( Text Transmission )
+    TXT(TXT),
+    /// THS ( True Heading and Status )
+    THS(THS),
+    /// HDT ( Heading, True )
+    HDT(HDT),
+    /// HDG ( Heading, Deviation & Variation )
+    HDG(HDG),
+    /// HDM ( Heading, Magnetic )
+    HDM(HDM),
+    /// ROT ( Rate Of Turn )
+    ROT(ROT),
+    /// VLW ( Dual Ground/Water Distance )
+    VLW(VLW),
+    /// RLM ( Return Link Message )
+    RLM(RLM),
+    /// Proprietary sentence ( `$P` followed by a manufacturer code )
+    Proprietary(ProprietarySentence),
+    /// Checksum valid sentence with a sentence ID this crate does not parse
+    Unknown(UnknownSentence),
+    /// Sentence parsed by a parser registered on a [`Registry`](crate::parser::Registry)
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    Custom(CustomSentence),
 }
 
 impl CommandTypes {
-    pub(crate) fn from_str(s: &str) -> Result<CommandTypes, &str> {
-        match s {
-            "GGA" => Ok(CommandTypes::GGA(GGA::default())),
-            "GSV" => Ok(CommandTypes::GSV(GSVPage::default())),
-            "GLL" => Ok(CommandTypes::GLL(GLL::default())),
-            "GSA" => Ok(CommandTypes::GSA(GSA::default())),
-            "VTG" => Ok(CommandTypes::VTG(VTG::default())),
-            "RMC" => Ok(CommandTypes::RMC(RMC::default())),
-            "DTM" => Ok(CommandTypes::DTM(DTM::default())),
-            "GBS" => Ok(CommandTypes::GBS(GBS::default())),
-            "GNS" => Ok(CommandTypes::GNS(GNS::default())),
-            _ => Err("Invalid command type"),
-        }
+    pub(crate) fn parse(tokens: &Tokens) -> Result<CommandTypes, Error> {
+        CommandTypesRef::parse(tokens)?
+            .into_owned()
+            .map_err(|e| e.in_sentence(tokens.sentence_id))
     }
+}
 
-    pub(crate) fn parse_commands(&mut self, command: Vec<String>) -> Result<CommandTypes, Error> {
-        match self {
-            CommandTypes::GGA(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::GGA(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::GSV(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::GSV(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::GLL(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::GLL(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::GSA(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::GSA(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::VTG(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::VTG(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::RMC(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::RMC(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::DTM(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::DTM(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::GBS(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::GBS(e.clone())),
-                Err(e) => Err(e),
-            },
-            CommandTypes::GNS(e) => match e.parse_command(command) {
-                Ok(e) => Ok(CommandTypes::GNS(e.clone())),
-                Err(e) => Err(e),
-            },
+/// Command types borrowing their text fields from the parsed line
+///
+/// Sentences with long text have their own borrowed type, short codes such as units and datum codes
+/// are kept inline in the owned sentence types, which is as cheap as a `&str`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandTypesRef<'a> {
+    /// GGA ( Global Positioning System Fix Data )
+    GGA(GGA),
+    /// GSV ( GNSS Satellites in View )
+    GSV(GSVPage),
+    /// GLL ( Geographic Position - Latitude/Longitude )
+    GLL(GLL),
+    /// GSA ( GPS DOP and Active Satellites )
+    GSA(GSA),
+    /// RMC ( Recommended Minimum Specific GPS/Transit Data )
+    RMC(RMC),
+    /// VTG ( Course Over Ground and Ground Speed )
+    VTG(VTG),
+    /// DTM ( Datum Reference )
+    DTM(DTM),
+    /// GBS ( GNSS Satellite Fault Detection )
+    GBS(GBS),
+    /// GNS ( GNSS Fix Data )
+    GNS(GNS),
+    /// ZDA ( Time & Date )
+    ZDA(ZDA),
+    /// GST ( GNSS Pseudorange Error Statistics )
+    GST(GST),
+    /// GRS ( GNSS Range Residuals )
+    GRS(GRS),
+    /// TXT ( Text Transmission )
+    TXT(TXTRef<'a>),
+    /// THS ( True Heading and Status )
+    THS(THS),
+    /// HDT ( Heading, True )
+    HDT(HDT),
+    /// HDG ( Heading, Deviation & Variation )
+    HDG(HDG),
+    /// HDM ( Heading, Magnetic )
+    HDM(HDM),
+    /// ROT ( Rate Of Turn )
+    ROT(ROT),
+    /// VLW ( Dual Ground/Water Distance )
+    VLW(VLW),
+    /// RLM ( Return Link Message )
+    RLM(RLMRef<'a>),
+    /// Proprietary sentence ( `$P` followed by a manufacturer code )
+    Proprietary(ProprietaryRef<'a>),
+    /// Checksum valid sentence with a sentence ID this crate does not parse
+    Unknown(UnknownRef<'a>),
+}
+
+impl<'a> CommandTypesRef<'a> {
+    pub(crate) fn parse(tokens: &Tokens<'a, '_>) -> Result<CommandTypesRef<'a>, Error> {
+        let command = tokens.fields;
+        if tokens.talker_id == TalkerIds::Proprietary {
+            let proprietary = ProprietaryRef::from_address(tokens.sentence_id)
+                .map_err(|e| e.in_sentence(tokens.sentence_id))?;
+            return Ok(CommandTypesRef::Proprietary(ProprietaryRef {
+                data: tokens.data,
+                field_count: tokens.fields.len(),
+                ..proprietary
+            }));
         }
+        let body: Result<CommandTypesRef<'a>, Error> = match tokens.sentence_id {
+            "GGA" => GGA::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GGA),
+            "GSV" => GSVPage::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GSV),
+            "GLL" => GLL::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GLL),
+            "GSA" => GSA::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GSA),
+            "VTG" => VTG::default()
+                .parse_command(command)
+                .map(CommandTypesRef::VTG),
+            "RMC" => RMC::default()
+                .parse_command(command)
+                .map(CommandTypesRef::RMC),
+            "DTM" => DTM::default()
+                .parse_command(command)
+                .map(CommandTypesRef::DTM),
+            "GBS" => GBS::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GBS),
+            "GNS" => GNS::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GNS),
+            "ZDA" => ZDA::default()
+                .parse_command(command)
+                .map(CommandTypesRef::ZDA),
+            "GST" => GST::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GST),
+            "GRS" => GRS::default()
+                .parse_command(command)
+                .map(CommandTypesRef::GRS),
+            "TXT" => TXTRef::parse(command, tokens.data).map(CommandTypesRef::TXT),
+            "THS" => THS::default()
+                .parse_command(command)
+                .map(CommandTypesRef::THS),
+            "HDT" => HDT::default()
+                .parse_command(command)
+                .map(CommandTypesRef::HDT),
+            "HDG" => HDG::default()
+                .parse_command(command)
+                .map(CommandTypesRef::HDG),
+            "HDM" => HDM::default()
+                .parse_command(command)
+                .map(CommandTypesRef::HDM),
+            "ROT" => ROT::default()
+                .parse_command(command)
+                .map(CommandTypesRef::ROT),
+            "VLW" => VLW::default()
+                .parse_command(command)
+                .map(CommandTypesRef::VLW),
+            "RLM" => RLMRef::parse(command).map(CommandTypesRef::RLM),
+            sentence_id => Ok(CommandTypesRef::Unknown(UnknownRef {
+                talker_id: tokens.talker_id.clone(),
+                sentence_id,
+                data: tokens.data,
+                field_count: tokens.fields.len(),
+            })),
+        };
+        body.map_err(|e| e.in_sentence(tokens.sentence_id))
+    }
+
+    /// Copy into owned [`CommandTypes`], fails when a text field exceeds the capacity of its owned counterpart
+    pub fn into_owned(self) -> Result<CommandTypes, Error> {
+        Ok(match self {
+            CommandTypesRef::GGA(e) => CommandTypes::GGA(e),
+            CommandTypesRef::GSV(e) => CommandTypes::GSV(e),
+            CommandTypesRef::GLL(e) => CommandTypes::GLL(e),
+            CommandTypesRef::GSA(e) => CommandTypes::GSA(e),
+            CommandTypesRef::RMC(e) => CommandTypes::RMC(e),
+            CommandTypesRef::VTG(e) => CommandTypes::VTG(e),
+            CommandTypesRef::DTM(e) => CommandTypes::DTM(e),
+            CommandTypesRef::GBS(e) => CommandTypes::GBS(e),
+            CommandTypesRef::GNS(e) => CommandTypes::GNS(e),
+            CommandTypesRef::ZDA(e) => CommandTypes::ZDA(e),
+            CommandTypesRef::GST(e) => CommandTypes::GST(e),
+            CommandTypesRef::GRS(e) => CommandTypes::GRS(e),
+            CommandTypesRef::TXT(e) => CommandTypes::TXT(e.into_owned()?),
+            CommandTypesRef::THS(e) => CommandTypes::THS(e),
+            CommandTypesRef::HDT(e) => CommandTypes::HDT(e),
+            CommandTypesRef::HDG(e) => CommandTypes::HDG(e),
+            CommandTypesRef::HDM(e) => CommandTypes::HDM(e),
+            CommandTypesRef::ROT(e) => CommandTypes::ROT(e),
+            CommandTypesRef::VLW(e) => CommandTypes::VLW(e),
+            CommandTypesRef::RLM(e) => CommandTypes::RLM(e.into_owned()?),
+            CommandTypesRef::Proprietary(e) => CommandTypes::Proprietary(e.into_owned()?),
+            CommandTypesRef::Unknown(e) => CommandTypes::Unknown(e.into_owned()?),
+        })
     }
 }
 
@@ -134,10 +1087,34 @@ impl Status {
             _ => Err("Invalid status"),
         }
     }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Status::Valid => "A",
+            Status::Invalid => "V",
+        }
+    }
 }
 
 /// Time struct
-#[derive(Debug, Clone, Default, PartialEq)]
+///
+/// Times order within a day, arithmetic wraps around midnight.
+/// # Examples
+/// ```
+/// use core::time::Duration;
+/// use rust_nmea::{parser::Parser, types::CommandTypes};
+/// let first = Parser::parse_line("$GPZDA,235959.995,01,03,2024,00,00*54").unwrap();
+/// let second = Parser::parse_line("$GPZDA,000000.005,02,03,2024,00,00*56").unwrap();
+/// if let (CommandTypes::ZDA(first), CommandTypes::ZDA(second)) = (first, second) {
+///     assert_eq!(first.time.nanosecond, 995_000_000);
+///     assert!(second.time < first.time);
+///     assert_eq!(second.time.duration_since(&first.time), Duration::from_millis(10));
+///     assert_eq!(first.time + Duration::from_millis(10), second.time);
+/// } else {
+///     panic!("Expected ZDA");
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Time {
     /// UTC hour
     pub hour: u8,
@@ -145,8 +1122,119 @@ pub struct Time {
     pub minute: u8,
     /// UTC second
     pub second: u8,
-    /// Decimal Seconds
-    pub decimal_seconds: u8,
+    /// Fraction of the second in nanoseconds, `.5` and `.50` are both 500000000, always below 1000000000
+    pub nanosecond: u32,
+}
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_DAY: u64 = 86_400 * NANOS_PER_SECOND;
+
+impl Time {
+    /// Parse time in hhmmss or hhmmss.sss format with up to 9 decimal places,
+    /// a second of 60 is accepted for leap seconds
+    pub(crate) fn from_str(s: &str) -> Result<Time, Error> {
+        if s.is_empty() {
+            return Err(Error::new(ErrorKind::MissingField));
+        }
+        let (hhmmss, fraction) = s.split_once('.').unwrap_or((s, ""));
+
+        if hhmmss.len() != 6 || !hhmmss.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(Error::new(ErrorKind::InvalidNumber));
+        }
+        if fraction.len() > 9 || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(Error::new(ErrorKind::InvalidNumber));
+        }
+
+        let two_digits = |digits: &str| {
+            u8::parse_decimal(digits).ok_or_else(|| Error::new(ErrorKind::InvalidNumber))
+        };
+        let hour = two_digits(&hhmmss[..2])?;
+        let minute = two_digits(&hhmmss[2..4])?;
+        let second = two_digits(&hhmmss[4..6])?;
+        let nanosecond = fraction
+            .bytes()
+            .chain(core::iter::repeat(b'0'))
+            .take(9)
+            .fold(0, |nanosecond, digit| {
+                nanosecond * 10 + u32::from(digit - b'0')
+            });
+        if hour > 23 || minute > 59 || second > 60 {
+            return Err(Error::new(ErrorKind::OutOfRange));
+        }
+        Ok(Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+
+    /// Time elapsed since midnight, a leap second counts as the first second of the next day
+    pub fn since_midnight(&self) -> Duration {
+        Duration::from_nanos(self.nanos_of_day())
+    }
+
+    /// Time elapsed since given earlier time, wrapping around midnight when `earlier` is later in the day
+    pub fn duration_since(&self, earlier: &Time) -> Duration {
+        let nanos =
+            (self.nanos_of_day() + NANOS_PER_DAY * 2 - earlier.nanos_of_day()) % NANOS_PER_DAY;
+        Duration::from_nanos(nanos)
+    }
+
+    fn nanos_of_day(&self) -> u64 {
+        (self.hour as u64 * 3600 + self.minute as u64 * 60 + self.second as u64) * NANOS_PER_SECOND
+            + self.nanosecond as u64
+    }
+
+    fn from_nanos_of_day(nanos: u64) -> Time {
+        let nanos = nanos % NANOS_PER_DAY;
+        let seconds = nanos / NANOS_PER_SECOND;
+        Time {
+            hour: (seconds / 3600) as u8,
+            minute: (seconds % 3600 / 60) as u8,
+            second: (seconds % 60) as u8,
+            nanosecond: (nanos % NANOS_PER_SECOND) as u32,
+        }
+    }
+}
+
+impl ops::Add<Duration> for Time {
+    type Output = Time;
+
+    fn add(self, duration: Duration) -> Time {
+        let nanos = (duration.as_nanos() % NANOS_PER_DAY as u128) as u64;
+        Time::from_nanos_of_day(self.nanos_of_day() + nanos)
+    }
+}
+
+impl ops::Sub<Duration> for Time {
+    type Output = Time;
+
+    fn sub(self, duration: Duration) -> Time {
+        let nanos = (duration.as_nanos() % NANOS_PER_DAY as u128) as u64;
+        Time::from_nanos_of_day(self.nanos_of_day() + NANOS_PER_DAY * 2 - nanos)
+    }
+}
+
+impl ops::AddAssign<Duration> for Time {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = core::mem::take(self) + duration;
+    }
+}
+
+impl ops::SubAssign<Duration> for Time {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = core::mem::take(self) - duration;
+    }
+}
+
+impl ops::Sub for Time {
+    type Output = Duration;
+
+    /// See [`Time::duration_since`]
+    fn sub(self, earlier: Time) -> Duration {
+        self.duration_since(&earlier)
+    }
 }
 
 /// Date struct
@@ -160,6 +1248,115 @@ pub struct Date {
     pub day: u8,
 }
 
+impl Date {
+    /// Parse date in ddmmyy format, years are kept as received
+    pub(crate) fn from_ddmmyy(s: &str) -> Result<Date, Error> {
+        if s.is_empty() {
+            return Err(Error::new(ErrorKind::MissingField));
+        }
+        if s.len() < 6 || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(Error::new(ErrorKind::InvalidNumber));
+        }
+        let (day, month, year) = match (
+            u8::parse_decimal(&s[..2]),
+            u8::parse_decimal(&s[2..4]),
+            usize::parse_decimal(&s[4..]),
+        ) {
+            (Some(day), Some(month), Some(year)) => (day, month, year),
+            _ => return Err(Error::new(ErrorKind::InvalidNumber)),
+        };
+        if !(1..=31).contains(&day) || !(1..=12).contains(&month) || year > 9999 {
+            return Err(Error::new(ErrorKind::OutOfRange));
+        }
+        Ok(Date { year, month, day })
+    }
+}
+
+/// Date and time at a fixed offset from UTC
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ZonedDateTime {
+    /// Local date
+    pub date: Date,
+    /// Local time
+    pub time: Time,
+    /// Offset from UTC in minutes, local time is UTC plus this offset
+    pub offset_minutes: i16,
+}
+
+impl ZonedDateTime {
+    /// Build local date and time from UTC date, UTC time and offset in minutes
+    pub fn from_utc(date: &Date, time: &Time, offset_minutes: i16) -> ZonedDateTime {
+        let local_seconds = utc_seconds(date, time) + offset_minutes as i64 * 60;
+        let (local_date, mut local_time) = from_utc_seconds(local_seconds);
+        local_time.nanosecond = time.nanosecond;
+        ZonedDateTime {
+            date: local_date,
+            time: local_time,
+            offset_minutes,
+        }
+    }
+
+    /// Seconds since Unix epoch (1970-01-01T00:00:00Z), the fraction of the second is not included
+    pub fn unix_timestamp(&self) -> i64 {
+        utc_seconds(&self.date, &self.time) - self.offset_minutes as i64 * 60
+    }
+}
+
+/// Days since Unix epoch for given civil date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Civil date for given days since Unix epoch
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn utc_seconds(date: &Date, time: &Time) -> i64 {
+    days_from_civil(date.year as i64, date.month as i64, date.day as i64) * 86400
+        + time.hour as i64 * 3600
+        + time.minute as i64 * 60
+        + time.second as i64
+}
+
+fn from_utc_seconds(seconds: i64) -> (Date, Time) {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let second_of_day = seconds.rem_euclid(86400);
+    (
+        Date {
+            year: year as usize,
+            month: month as u8,
+            day: day as u8,
+        },
+        Time {
+            hour: (second_of_day / 3600) as u8,
+            minute: (second_of_day % 3600 / 60) as u8,
+            second: (second_of_day % 60) as u8,
+            nanosecond: 0,
+        },
+    )
+}
+
 /// Cordinate struct
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Cordinate {
@@ -169,6 +1366,228 @@ pub struct Cordinate {
     pub minute: f64,
 }
 
+impl Cordinate {
+    /// Parse cordinate in ddmm.mmmmm (latitude) or dddmm.mmmmm (longitude) format
+    ///
+    /// Minutes must be below 60 and the angle at most `max_degrees`, 90 for latitudes and 180 for longitudes
+    pub(crate) fn from_str(s: &str, max_degrees: usize) -> Result<Cordinate, Error> {
+        if s.is_empty() {
+            return Err(Error::new(ErrorKind::MissingField));
+        }
+        // Cordinates are unsigned, the hemisphere is a separate field
+        if !s.bytes().all(|byte| byte.is_ascii_digit() || byte == b'.') {
+            return Err(Error::new(ErrorKind::InvalidNumber));
+        }
+        // Minutes are the two digits before the decimal point, degrees whatever precedes them
+        let minute_start = s
+            .bytes()
+            .position(|byte| byte == b'.')
+            .unwrap_or(s.len())
+            .checked_sub(2)
+            .ok_or(Error::new(ErrorKind::InvalidNumber))?;
+        let (degree, minute) = s.split_at(minute_start);
+        let cordinate = match (usize::parse_decimal(degree), f64::parse_decimal(minute)) {
+            (Some(degree), Some(minute)) => Cordinate { degree, minute },
+            _ => return Err(Error::new(ErrorKind::InvalidNumber)),
+        };
+        if cordinate.minute >= 60.0 || cordinate.decimal_degrees() > max_degrees as f64 {
+            return Err(Error::new(ErrorKind::OutOfRange));
+        }
+        Ok(cordinate)
+    }
+
+    /// Unsigned angle in decimal degrees
+    pub fn decimal_degrees(&self) -> f64 {
+        self.degree as f64 + self.minute / 60.0
+    }
+
+    /// Cordinate of given unsigned angle in decimal degrees
+    pub fn from_decimal_degrees(degrees: f64) -> Cordinate {
+        let degree = degrees as usize;
+        Cordinate {
+            degree,
+            minute: (degrees - degree as f64) * 60.0,
+        }
+    }
+
+    /// Angle in degrees, minutes and seconds
+    pub fn dms(&self) -> Dms {
+        let minutes = self.minute as u8;
+        Dms {
+            degrees: self.degree,
+            minutes,
+            seconds: (self.minute - minutes as f64) * 60.0,
+        }
+    }
+
+    /// Cordinate of given angle in degrees, minutes and seconds
+    pub fn from_dms(dms: &Dms) -> Cordinate {
+        Cordinate {
+            degree: dms.degrees,
+            minute: dms.minutes as f64 + dms.seconds / 60.0,
+        }
+    }
+}
+
+/// Angle in degrees, minutes and seconds
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dms {
+    /// Whole degrees
+    pub degrees: usize,
+    /// Whole minutes
+    pub minutes: u8,
+    /// Seconds
+    pub seconds: f64,
+}
+
+/// Latitude with its hemisphere, positive north of the equator in decimal degrees
+/// # Examples
+/// ```
+/// use rust_nmea::{parser::Parser, types::{CommandTypes, Dms}};
+/// let line = "$GPGLL,4717.11437,S,00833.91522,W,083559.00,A,A*64";
+/// if let Ok(CommandTypes::GLL(gll)) = Parser::parse_line(line) {
+///     let latitude = gll.latitude().unwrap();
+///     assert!((latitude.decimal_degrees() + 47.2852395).abs() < 1e-9);
+///     let dms = latitude.dms();
+///     assert_eq!((dms.degrees, dms.minutes), (47, 17));
+///     assert!((dms.seconds - 6.8622).abs() < 1e-9);
+///     assert!((gll.longitude().unwrap().decimal_degrees() + 8.565254).abs() < 1e-6);
+/// } else {
+///     panic!("Expected GLL");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Latitude {
+    /// Unsigned degrees and minutes
+    pub cordinate: Cordinate,
+    /// N=North/S=South indicator
+    pub direction: CardinalDirection,
+}
+
+impl Latitude {
+    /// Latitude of given signed decimal degrees, fails outside -90 to 90 degrees
+    pub fn from_decimal_degrees(degrees: f64) -> Result<Latitude, Error> {
+        if !(-90.0..=90.0).contains(&degrees) {
+            return Err(Error::new(ErrorKind::OutOfRange));
+        }
+        let (degrees, direction) = if degrees < 0.0 {
+            (-degrees, CardinalDirection::South)
+        } else {
+            (degrees, CardinalDirection::North)
+        };
+        Ok(Latitude {
+            cordinate: Cordinate::from_decimal_degrees(degrees),
+            direction,
+        })
+    }
+
+    /// Signed decimal degrees, negative south of the equator
+    pub fn decimal_degrees(&self) -> f64 {
+        signed(self.cordinate.decimal_degrees(), &self.direction)
+    }
+
+    /// Latitude of given angle and hemisphere, fails beyond 90 degrees or with minutes or seconds of 60 or more
+    /// # Examples
+    /// ```
+    /// use rust_nmea::types::{CardinalDirection, Dms, ErrorKind, Latitude};
+    /// let dms = |minutes, seconds| Dms { degrees: 47, minutes, seconds };
+    /// assert!(Latitude::from_dms(&dms(59, 59.5), CardinalDirection::North).is_ok());
+    /// for bad in [dms(60, 0.0), dms(200, 0.0), dms(17, 60.0), dms(17, -1.0), dms(17, f64::NAN)] {
+    ///     let error = Latitude::from_dms(&bad, CardinalDirection::North).unwrap_err();
+    ///     assert_eq!(error.kind, ErrorKind::OutOfRange);
+    /// }
+    /// let beyond = Dms { degrees: 90, minutes: 0, seconds: 1.0 };
+    /// assert!(Latitude::from_dms(&beyond, CardinalDirection::South).is_err());
+    /// ```
+    pub fn from_dms(dms: &Dms, direction: CardinalDirection) -> Result<Latitude, Error> {
+        let cordinate = dms_cordinate(dms, 90)?;
+        Ok(Latitude {
+            cordinate,
+            direction,
+        })
+    }
+
+    /// Unsigned angle in degrees, minutes and seconds, the hemisphere is in [`Latitude::direction`]
+    pub fn dms(&self) -> Dms {
+        self.cordinate.dms()
+    }
+}
+
+/// Longitude with its hemisphere, positive east of the prime meridian in decimal degrees
+#[derive(Debug, Clone, PartialEq)]
+pub struct Longitude {
+    /// Unsigned degrees and minutes
+    pub cordinate: Cordinate,
+    /// E=East/W=West indicator
+    pub direction: CardinalDirection,
+}
+
+impl Longitude {
+    /// Longitude of given signed decimal degrees, fails outside -180 to 180 degrees
+    pub fn from_decimal_degrees(degrees: f64) -> Result<Longitude, Error> {
+        if !(-180.0..=180.0).contains(&degrees) {
+            return Err(Error::new(ErrorKind::OutOfRange));
+        }
+        let (degrees, direction) = if degrees < 0.0 {
+            (-degrees, CardinalDirection::West)
+        } else {
+            (degrees, CardinalDirection::East)
+        };
+        Ok(Longitude {
+            cordinate: Cordinate::from_decimal_degrees(degrees),
+            direction,
+        })
+    }
+
+    /// Signed decimal degrees, negative west of the prime meridian
+    pub fn decimal_degrees(&self) -> f64 {
+        signed(self.cordinate.decimal_degrees(), &self.direction)
+    }
+
+    /// Longitude of given angle and hemisphere, fails beyond 180 degrees or with minutes or seconds of 60 or more
+    /// # Examples
+    /// ```
+    /// use rust_nmea::types::{CardinalDirection, Dms, ErrorKind, Longitude};
+    /// let dms = Dms { degrees: 0, minutes: 200, seconds: 0.0 };
+    /// let error = Longitude::from_dms(&dms, CardinalDirection::East).unwrap_err();
+    /// assert_eq!(error.kind, ErrorKind::OutOfRange);
+    /// let dms = Dms { degrees: 179, minutes: 59, seconds: 59.5 };
+    /// assert!(Longitude::from_dms(&dms, CardinalDirection::West).is_ok());
+    /// ```
+    pub fn from_dms(dms: &Dms, direction: CardinalDirection) -> Result<Longitude, Error> {
+        let cordinate = dms_cordinate(dms, 180)?;
+        Ok(Longitude {
+            cordinate,
+            direction,
+        })
+    }
+
+    /// Unsigned angle in degrees, minutes and seconds, the hemisphere is in [`Longitude::direction`]
+    pub fn dms(&self) -> Dms {
+        self.cordinate.dms()
+    }
+}
+
+/// Cordinate of given angle, minutes and seconds must be below 60 so the cordinate encodes as received
+fn dms_cordinate(dms: &Dms, max_degrees: usize) -> Result<Cordinate, Error> {
+    if dms.minutes >= 60 || !(0.0..60.0).contains(&dms.seconds) {
+        return Err(Error::new(ErrorKind::OutOfRange));
+    }
+    let cordinate = Cordinate::from_dms(dms);
+    if cordinate.decimal_degrees() > max_degrees as f64 {
+        return Err(Error::new(ErrorKind::OutOfRange));
+    }
+    Ok(cordinate)
+}
+
+/// Negate degrees south of the equator and west of the prime meridian
+fn signed(degrees: f64, direction: &CardinalDirection) -> f64 {
+    match direction {
+        CardinalDirection::South | CardinalDirection::West => -degrees,
+        _ => degrees,
+    }
+}
+
 /// GGA command status
 #[derive(Debug, Clone, PartialEq)]
 pub enum GGAStatus {
@@ -186,6 +1605,19 @@ pub enum GGAStatus {
     DeadReckoning,
 }
 
+impl GGAStatus {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            GGAStatus::Invalid => "0",
+            GGAStatus::S2d3D => "1",
+            GGAStatus::Dgnss => "2",
+            GGAStatus::FixedRtk => "3",
+            GGAStatus::FloatRtk => "4",
+            GGAStatus::DeadReckoning => "5",
+        }
+    }
+}
+
 /// ModeIndicator struct
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModeIndicator {
@@ -239,6 +1671,58 @@ impl ModeIndicator {
             _ => None,
         }
     }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ModeIndicator::NoFix => "N",
+            ModeIndicator::Autonomous => "A",
+            ModeIndicator::Differential => "D",
+            ModeIndicator::Precise => "P",
+            ModeIndicator::RealTime => "R",
+            ModeIndicator::FloatRtk => "F",
+            ModeIndicator::Estimate => "E",
+            ModeIndicator::Manual => "M",
+            ModeIndicator::Simulation => "S",
+        }
+    }
+}
+
+/// Heading mode indicator, used by THS
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeadingModeIndicator {
+    /// Autonomous mode
+    Autonomous,
+    /// Estimated (dead reckoning) mode
+    Estimated,
+    /// Manual input mode
+    Manual,
+    /// Simulator mode
+    Simulator,
+    /// Data not valid
+    NotValid,
+}
+
+impl HeadingModeIndicator {
+    pub(crate) fn from_str(s: &str) -> Result<HeadingModeIndicator, &str> {
+        match s {
+            "A" => Ok(HeadingModeIndicator::Autonomous),
+            "E" => Ok(HeadingModeIndicator::Estimated),
+            "M" => Ok(HeadingModeIndicator::Manual),
+            "S" => Ok(HeadingModeIndicator::Simulator),
+            "V" => Ok(HeadingModeIndicator::NotValid),
+            _ => Err("Invalid heading mode indicator"),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            HeadingModeIndicator::Autonomous => "A",
+            HeadingModeIndicator::Estimated => "E",
+            HeadingModeIndicator::Manual => "M",
+            HeadingModeIndicator::Simulator => "S",
+            HeadingModeIndicator::NotValid => "V",
+        }
+    }
 }
 
 /// GSA command operation mode
@@ -258,6 +1742,39 @@ impl GSAOperationMode {
             _ => Err("Invalid operation mode"),
         }
     }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            GSAOperationMode::Manual => "M",
+            GSAOperationMode::Automatic => "A",
+        }
+    }
+}
+
+/// GRS command residual computation mode
+#[derive(Debug, Clone, PartialEq)]
+pub enum GRSMode {
+    /// Residuals were used to calculate the position given in the matching GGA
+    UsedInGGA,
+    /// Residuals were recomputed after the GGA position was computed
+    Recomputed,
+}
+
+impl GRSMode {
+    pub(crate) fn from_str(s: &str) -> Result<GRSMode, &str> {
+        match s {
+            "0" => Ok(GRSMode::UsedInGGA),
+            "1" => Ok(GRSMode::Recomputed),
+            _ => Err("Invalid GRS mode"),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            GRSMode::UsedInGGA => "0",
+            GRSMode::Recomputed => "1",
+        }
+    }
 }
 
 /// Navigation Mode struct
@@ -280,6 +1797,14 @@ impl NavigationMode {
             _ => Err("Invalid navigation mode"),
         }
     }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            NavigationMode::NoFix => "1",
+            NavigationMode::Fix2D => "2",
+            NavigationMode::Fix3D => "3",
+        }
+    }
 }
 
 /// VTG command speed unit
@@ -302,6 +1827,79 @@ impl VTGUnit {
             _ => Err("Invalid VTG unit"),
         }
     }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            VTGUnit::Knots => "N",
+            VTGUnit::Kmh => "K",
+            VTGUnit::Mph => "M",
+        }
+    }
+}
+
+/// TXT message type
+#[derive(Debug, Clone, PartialEq)]
+pub enum TXTMessageType {
+    /// Error message (identifier 00)
+    Error,
+    /// Warning message (identifier 01)
+    Warning,
+    /// Notice message (identifier 02)
+    Notice,
+    /// User message (identifier 07)
+    User,
+    /// Other identifier
+    Other(u8),
+}
+
+impl TXTMessageType {
+    pub(crate) fn from_identifier(identifier: u8) -> TXTMessageType {
+        match identifier {
+            0 => TXTMessageType::Error,
+            1 => TXTMessageType::Warning,
+            2 => TXTMessageType::Notice,
+            7 => TXTMessageType::User,
+            e => TXTMessageType::Other(e),
+        }
+    }
+}
+
+/// RLM message code
+#[derive(Debug, Clone, PartialEq)]
+pub enum RLMMessageCode {
+    /// Reserved code
+    Reserved,
+    /// Acknowledgement service
+    Acknowledgement,
+    /// Command service
+    Command,
+    /// Message service
+    Message,
+    /// Test service
+    Test,
+}
+
+impl RLMMessageCode {
+    pub(crate) fn from_str(s: &str) -> Result<RLMMessageCode, &str> {
+        match s {
+            "0" => Ok(RLMMessageCode::Reserved),
+            "1" => Ok(RLMMessageCode::Acknowledgement),
+            "2" => Ok(RLMMessageCode::Command),
+            "3" => Ok(RLMMessageCode::Message),
+            "F" => Ok(RLMMessageCode::Test),
+            _ => Err("Invalid RLM message code"),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            RLMMessageCode::Reserved => "0",
+            RLMMessageCode::Acknowledgement => "1",
+            RLMMessageCode::Command => "2",
+            RLMMessageCode::Message => "3",
+            RLMMessageCode::Test => "F",
+        }
+    }
 }
 
 /// Navigation Status struct
@@ -331,50 +1929,428 @@ pub enum CardinalDirection {
 }
 
 impl CardinalDirection {
-    pub(crate) fn from_char(s: char) -> Option<CardinalDirection> {
+    pub(crate) fn from_str(s: &str) -> Result<CardinalDirection, &str> {
         match s {
-            'N' => Some(CardinalDirection::North),
-            'S' => Some(CardinalDirection::South),
-            'E' => Some(CardinalDirection::East),
-            'W' => Some(CardinalDirection::West),
-            _ => None,
+            "N" => Ok(CardinalDirection::North),
+            "S" => Ok(CardinalDirection::South),
+            "E" => Ok(CardinalDirection::East),
+            "W" => Ok(CardinalDirection::West),
+            _ => Err("Invalid cardinal direction"),
+        }
+    }
+
+    /// Parse an `E`/`W` field, e.g. of magnetic variation
+    pub(crate) fn east_west(s: &str) -> Result<CardinalDirection, &str> {
+        match CardinalDirection::from_str(s)? {
+            e @ (CardinalDirection::East | CardinalDirection::West) => Ok(e),
+            _ => Err("Invalid east/west direction"),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            CardinalDirection::North => "N",
+            CardinalDirection::South => "S",
+            CardinalDirection::East => "E",
+            CardinalDirection::West => "W",
         }
     }
 }
 
-/// Talker IDs
+/// Talker IDs (IEC 61162-1)
 #[derive(Debug, Clone, PartialEq)]
 pub enum TalkerIds {
+    /// Independent AIS base station
+    AB,
+    /// Dependent AIS base station
+    AD,
+    /// Autopilot, general
+    AG,
+    /// Mobile AIS station
+    AI,
+    /// AIS aids to navigation station
+    AN,
+    /// Autopilot, magnetic
+    AP,
+    /// AIS receiving station
+    AR,
+    /// AIS station, limited base station
+    AS,
+    /// AIS transmitting station
+    AT,
+    /// AIS simplex repeater station
+    AX,
+    /// BeiDou (legacy)
+    BD,
+    /// Bilge system
+    BI,
+    /// Bridge navigational watch alarm system
+    BN,
+    /// Central alarm management
+    CA,
+    /// Digital selective calling (DSC)
+    CD,
+    /// Data receiver
+    CR,
+    /// Satellite communications
+    CS,
+    /// Radio-telephone (MF/HF)
+    CT,
+    /// Radio-telephone (VHF)
+    CV,
+    /// Scanning receiver
+    CX,
+    /// DECCA navigator
+    DE,
+    /// Direction finder
+    DF,
+    /// Magnetic water speed log, obsolete code superseded by [`TalkerIds::VM`]
+    DM,
+    /// Dynamic positioning
+    DP,
+    /// Duplex repeater station
+    DU,
+    /// Electronic chart system (ECS)
+    EC,
+    /// Electronic chart display and information system (ECDIS)
+    EI,
+    /// Emergency position indicating radio beacon (EPIRB)
+    EP,
+    /// Engine room monitoring systems
+    ER,
+    /// Fire door controller/monitoring point
+    FD,
+    /// Fire extinguisher system
+    FE,
+    /// Fire detection point
+    FR,
+    /// Fire sprinkler system
+    FS,
     /// Galileo
     GA,
     /// BeiDou
     GB,
-    /// GPS
-    GP,
+    /// NavIC (IRNSS)
+    GI,
     /// GLONASS
     GL,
     /// Combined GNSS position.
     GN,
+    /// GPS
+    GP,
     /// QZSS
     GQ,
+    /// Heading, magnetic compass
+    HC,
+    /// Hull door controller/monitoring panel
+    HD,
+    /// Heading, north seeking gyro
+    HE,
+    /// Heading, fluxgate
+    HF,
+    /// Heading, non-north seeking gyro
+    HN,
+    /// Hull stress monitoring
+    HS,
+    /// Integrated instrumentation
+    II,
+    /// Integrated navigation
+    IN,
+    /// Alarm and monitoring system
+    JA,
+    /// Water monitoring system
+    JB,
+    /// Power management system
+    JC,
+    /// Propulsion control system
+    JD,
+    /// Engine control console
+    JE,
+    /// Propulsion boiler
+    JF,
+    /// Auxiliary boiler
+    JG,
+    /// Electronic governor system
+    JH,
+    /// Loran C
+    LC,
+    /// Navigation light controller
+    NL,
+    /// Radar and/or radar plotting
+    RA,
+    /// Record book
+    RB,
+    /// Propulsion machinery including remote control
+    RC,
+    /// Rudder angle indicator
+    RI,
+    /// Physical shore AIS station
+    SA,
+    /// Sounder, depth
+    SD,
+    /// Steering gear/steering engine
+    SG,
+    /// Electronic positioning system, other/general
+    SN,
+    /// Sounder, scanning
+    SS,
+    /// Track control system
+    TC,
+    /// Turn rate indicator
+    TI,
+    /// Microprocessor controller
+    UP,
+    /// VHF data exchange system (VDES), ASM
+    VA,
+    /// Velocity sensor, doppler, other/general
+    VD,
+    /// Velocity sensor, speed log, water, magnetic
+    VM,
+    /// Voyage data recorder
+    VR,
+    /// VHF data exchange system (VDES), satellite
+    VS,
+    /// VHF data exchange system (VDES), terrestrial
+    VT,
+    /// Velocity sensor, speed log, water, mechanical
+    VW,
+    /// Watertight door controller/monitoring panel
+    WD,
+    /// Weather instruments
+    WI,
+    /// Water level detection system
+    WL,
+    /// Transducer
+    YX,
+    /// Timekeeper, atomic clock
+    ZA,
+    /// Timekeeper, chronometer
+    ZC,
+    /// Timekeeper, quartz
+    ZQ,
+    /// Timekeeper, radio update
+    ZV,
+    /// Proprietary sentence, `$P` followed by a manufacturer code
+    Proprietary,
+    /// Talker ID not listed in the standard table, e.g. user configured `U0` to `U9`
+    Other([u8; 2]),
 }
 
 impl TalkerIds {
-    /// Check if Talker ID is correct
+    /// Check if Talker ID is correct, any two uppercase letters or digits are accepted
     pub fn is_correct(code: &str) -> bool {
-        matches!(code, "$GA" | "$GB" | "$GP" | "$GL" | "$GN" | "$GQ")
+        let code = code.trim_start_matches(['$', '!']);
+        code.len() == 2
+            && code
+                .bytes()
+                .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
     }
 
-    /// Parse Talker ID
+    /// Talker ID as two letter code, `P` for proprietary sentences
+    pub fn as_str(&self) -> &str {
+        match self {
+            TalkerIds::AB => "AB",
+            TalkerIds::AD => "AD",
+            TalkerIds::AG => "AG",
+            TalkerIds::AI => "AI",
+            TalkerIds::AN => "AN",
+            TalkerIds::AP => "AP",
+            TalkerIds::AR => "AR",
+            TalkerIds::AS => "AS",
+            TalkerIds::AT => "AT",
+            TalkerIds::AX => "AX",
+            TalkerIds::BD => "BD",
+            TalkerIds::BI => "BI",
+            TalkerIds::BN => "BN",
+            TalkerIds::CA => "CA",
+            TalkerIds::CD => "CD",
+            TalkerIds::CR => "CR",
+            TalkerIds::CS => "CS",
+            TalkerIds::CT => "CT",
+            TalkerIds::CV => "CV",
+            TalkerIds::CX => "CX",
+            TalkerIds::DE => "DE",
+            TalkerIds::DF => "DF",
+            TalkerIds::DM => "DM",
+            TalkerIds::DP => "DP",
+            TalkerIds::DU => "DU",
+            TalkerIds::EC => "EC",
+            TalkerIds::EI => "EI",
+            TalkerIds::EP => "EP",
+            TalkerIds::ER => "ER",
+            TalkerIds::FD => "FD",
+            TalkerIds::FE => "FE",
+            TalkerIds::FR => "FR",
+            TalkerIds::FS => "FS",
+            TalkerIds::GA => "GA",
+            TalkerIds::GB => "GB",
+            TalkerIds::GI => "GI",
+            TalkerIds::GL => "GL",
+            TalkerIds::GN => "GN",
+            TalkerIds::GP => "GP",
+            TalkerIds::GQ => "GQ",
+            TalkerIds::HC => "HC",
+            TalkerIds::HD => "HD",
+            TalkerIds::HE => "HE",
+            TalkerIds::HF => "HF",
+            TalkerIds::HN => "HN",
+            TalkerIds::HS => "HS",
+            TalkerIds::II => "II",
+            TalkerIds::IN => "IN",
+            TalkerIds::JA => "JA",
+            TalkerIds::JB => "JB",
+            TalkerIds::JC => "JC",
+            TalkerIds::JD => "JD",
+            TalkerIds::JE => "JE",
+            TalkerIds::JF => "JF",
+            TalkerIds::JG => "JG",
+            TalkerIds::JH => "JH",
+            TalkerIds::LC => "LC",
+            TalkerIds::NL => "NL",
+            TalkerIds::RA => "RA",
+            TalkerIds::RB => "RB",
+            TalkerIds::RC => "RC",
+            TalkerIds::RI => "RI",
+            TalkerIds::SA => "SA",
+            TalkerIds::SD => "SD",
+            TalkerIds::SG => "SG",
+            TalkerIds::SN => "SN",
+            TalkerIds::SS => "SS",
+            TalkerIds::TC => "TC",
+            TalkerIds::TI => "TI",
+            TalkerIds::UP => "UP",
+            TalkerIds::VA => "VA",
+            TalkerIds::VD => "VD",
+            TalkerIds::VM => "VM",
+            TalkerIds::VR => "VR",
+            TalkerIds::VS => "VS",
+            TalkerIds::VT => "VT",
+            TalkerIds::VW => "VW",
+            TalkerIds::WD => "WD",
+            TalkerIds::WI => "WI",
+            TalkerIds::WL => "WL",
+            TalkerIds::YX => "YX",
+            TalkerIds::ZA => "ZA",
+            TalkerIds::ZC => "ZC",
+            TalkerIds::ZQ => "ZQ",
+            TalkerIds::ZV => "ZV",
+            TalkerIds::Proprietary => "P",
+            TalkerIds::Other(code) => core::str::from_utf8(code).unwrap_or_default(),
+        }
+    }
+
+    /// Parse Talker ID, unknown talkers are kept as [`TalkerIds::Other`]
+    /// # Examples
+    /// ```
+    /// use rust_nmea::types::TalkerIds;
+    /// assert_eq!(TalkerIds::parse("$GI"), TalkerIds::GI);
+    /// assert_eq!(TalkerIds::parse("IN"), TalkerIds::IN);
+    /// assert_eq!(TalkerIds::parse("U1"), TalkerIds::Other(*b"U1"));
+    /// assert_eq!(TalkerIds::Other(*b"U1").as_str(), "U1");
+    /// ```
     pub fn parse(code: &str) -> TalkerIds {
-        match code {
-            "$GA" => TalkerIds::GA,
-            "$GB" => TalkerIds::GB,
-            "$GP" => TalkerIds::GP,
-            "$GL" => TalkerIds::GL,
-            "$GN" => TalkerIds::GN,
-            "$GQ" => TalkerIds::GQ,
-            _ => unreachable!(),
+        match code.trim_start_matches(['$', '!']).as_bytes() {
+            [first, second] => TalkerIds::from_code([*first, *second]),
+            b"P" => TalkerIds::Proprietary,
+            code => {
+                let mut other = [b' '; 2];
+                for (byte, code) in other.iter_mut().zip(code) {
+                    *byte = *code;
+                }
+                TalkerIds::Other(other)
+            }
+        }
+    }
+
+    /// Talker ID of a two letter code
+    pub(crate) fn from_code(code: [u8; 2]) -> TalkerIds {
+        match &code {
+            b"AB" => TalkerIds::AB,
+            b"AD" => TalkerIds::AD,
+            b"AG" => TalkerIds::AG,
+            b"AI" => TalkerIds::AI,
+            b"AN" => TalkerIds::AN,
+            b"AP" => TalkerIds::AP,
+            b"AR" => TalkerIds::AR,
+            b"AS" => TalkerIds::AS,
+            b"AT" => TalkerIds::AT,
+            b"AX" => TalkerIds::AX,
+            b"BD" => TalkerIds::BD,
+            b"BI" => TalkerIds::BI,
+            b"BN" => TalkerIds::BN,
+            b"CA" => TalkerIds::CA,
+            b"CD" => TalkerIds::CD,
+            b"CR" => TalkerIds::CR,
+            b"CS" => TalkerIds::CS,
+            b"CT" => TalkerIds::CT,
+            b"CV" => TalkerIds::CV,
+            b"CX" => TalkerIds::CX,
+            b"DE" => TalkerIds::DE,
+            b"DF" => TalkerIds::DF,
+            b"DM" => TalkerIds::DM,
+            b"DP" => TalkerIds::DP,
+            b"DU" => TalkerIds::DU,
+            b"EC" => TalkerIds::EC,
+            b"EI" => TalkerIds::EI,
+            b"EP" => TalkerIds::EP,
+            b"ER" => TalkerIds::ER,
+            b"FD" => TalkerIds::FD,
+            b"FE" => TalkerIds::FE,
+            b"FR" => TalkerIds::FR,
+            b"FS" => TalkerIds::FS,
+            b"GA" => TalkerIds::GA,
+            b"GB" => TalkerIds::GB,
+            b"GI" => TalkerIds::GI,
+            b"GL" => TalkerIds::GL,
+            b"GN" => TalkerIds::GN,
+            b"GP" => TalkerIds::GP,
+            b"GQ" => TalkerIds::GQ,
+            b"HC" => TalkerIds::HC,
+            b"HD" => TalkerIds::HD,
+            b"HE" => TalkerIds::HE,
+            b"HF" => TalkerIds::HF,
+            b"HN" => TalkerIds::HN,
+            b"HS" => TalkerIds::HS,
+            b"II" => TalkerIds::II,
+            b"IN" => TalkerIds::IN,
+            b"JA" => TalkerIds::JA,
+            b"JB" => TalkerIds::JB,
+            b"JC" => TalkerIds::JC,
+            b"JD" => TalkerIds::JD,
+            b"JE" => TalkerIds::JE,
+            b"JF" => TalkerIds::JF,
+            b"JG" => TalkerIds::JG,
+            b"JH" => TalkerIds::JH,
+            b"LC" => TalkerIds::LC,
+            b"NL" => TalkerIds::NL,
+            b"RA" => TalkerIds::RA,
+            b"RB" => TalkerIds::RB,
+            b"RC" => TalkerIds::RC,
+            b"RI" => TalkerIds::RI,
+            b"SA" => TalkerIds::SA,
+            b"SD" => TalkerIds::SD,
+            b"SG" => TalkerIds::SG,
+            b"SN" => TalkerIds::SN,
+            b"SS" => TalkerIds::SS,
+            b"TC" => TalkerIds::TC,
+            b"TI" => TalkerIds::TI,
+            b"UP" => TalkerIds::UP,
+            b"VA" => TalkerIds::VA,
+            b"VD" => TalkerIds::VD,
+            b"VM" => TalkerIds::VM,
+            b"VR" => TalkerIds::VR,
+            b"VS" => TalkerIds::VS,
+            b"VT" => TalkerIds::VT,
+            b"VW" => TalkerIds::VW,
+            b"WD" => TalkerIds::WD,
+            b"WI" => TalkerIds::WI,
+            b"WL" => TalkerIds::WL,
+            b"YX" => TalkerIds::YX,
+            b"ZA" => TalkerIds::ZA,
+            b"ZC" => TalkerIds::ZC,
+            b"ZQ" => TalkerIds::ZQ,
+            b"ZV" => TalkerIds::ZV,
+            _ => TalkerIds::Other(code),
         }
     }
 }
@@ -0,0 +1,193 @@
+//! Parallel parsing of whole logs with `BatchParser`
+#![cfg(feature = "rayon")]
+
+use std::fs;
+use std::sync::Mutex;
+
+use rust_nmea::{
+    batch::{BatchParser, ParseStats},
+    parser::Parser,
+    types::{CommandTypes, Error},
+};
+
+/// Log with a distinct time on every line, failing and blank lines in between
+fn log(lines: usize, line_ending: &str) -> String {
+    (0..lines)
+        .map(|index| match index % 7 {
+            3 => "$GPZDA,broken*00".to_string(),
+            5 => String::new(),
+            _ => zda(index),
+        })
+        .map(|line| line + line_ending)
+        .collect()
+}
+
+fn zda(index: usize) -> String {
+    let body = format!(
+        "GPZDA,{:02}{:02}{:02}.00,01,03,2024,00,00",
+        index / 3600 % 24,
+        index / 60 % 60,
+        index % 60
+    );
+    let checksum = body.bytes().fold(0, |checksum, byte| checksum ^ byte);
+    format!("${}*{:02X}", body, checksum)
+}
+
+fn sequential(log: &str) -> Vec<Result<CommandTypes, Error>> {
+    log.lines().map(Parser::parse_line).collect()
+}
+
+/// Line number and result of a line
+type LineResult = (usize, Result<CommandTypes, Error>);
+
+fn for_each_results(parser: BatchParser, bytes: &[u8]) -> (Vec<LineResult>, ParseStats) {
+    let results = Mutex::new(Vec::new());
+    let stats = parser.for_each(bytes, |line_number, result| {
+        results.lock().unwrap().push((line_number, result))
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(line_number, _)| *line_number);
+    (results, stats)
+}
+
+#[test]
+fn chunks_split_at_any_byte_keep_lines_whole() {
+    let log = log(20, "\r\n");
+    let expected = sequential(&log);
+    for chunk_size in 1..=log.len() + 1 {
+        let batch = BatchParser::new()
+            .chunk_size(chunk_size)
+            .parse(log.as_bytes());
+        assert!(
+            batch.results().eq(expected.iter()),
+            "chunk size {}",
+            chunk_size
+        );
+        assert_eq!(batch.stats().lines, 20);
+        for chunk in &batch.chunks {
+            let start = chunk.byte_offset as usize;
+            assert!(start == 0 || log.as_bytes()[start - 1] == b'\n');
+        }
+    }
+}
+
+#[test]
+fn line_numbers_and_offsets_follow_chunks() {
+    let log = log(100, "\n");
+    let batch = BatchParser::new().chunk_size(64).parse(log.as_bytes());
+    assert!(batch.chunks.len() > 1);
+
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(log.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let mut line_number = 1;
+    for chunk in &batch.chunks {
+        assert_eq!(chunk.first_line, line_number);
+        assert_eq!(chunk.byte_offset as usize, line_starts[line_number - 1]);
+        line_number += chunk.results.len();
+    }
+    assert_eq!(line_number, 101);
+}
+
+#[test]
+fn crlf_and_lf_give_the_same_results() {
+    let crlf = BatchParser::new()
+        .chunk_size(100)
+        .parse(log(50, "\r\n").as_bytes());
+    let lf = BatchParser::new()
+        .chunk_size(100)
+        .parse(log(50, "\n").as_bytes());
+    assert!(crlf.results().eq(lf.results()));
+    assert_eq!(crlf.stats(), lf.stats());
+}
+
+#[test]
+fn last_line_without_line_ending_is_parsed() {
+    let log = log(10, "\n");
+    let log = log.trim_end_matches('\n');
+    let batch = BatchParser::new().chunk_size(16).parse(log.as_bytes());
+    assert!(batch.results().eq(sequential(log).iter()));
+    assert_eq!(batch.stats().lines, 10);
+}
+
+#[test]
+fn empty_input_has_no_lines() {
+    let batch = BatchParser::new().parse(b"");
+    assert!(batch.chunks.is_empty());
+    assert_eq!(batch.stats(), ParseStats::default());
+
+    let (results, stats) = for_each_results(BatchParser::new(), b"");
+    assert!(results.is_empty());
+    assert_eq!(stats, ParseStats::default());
+}
+
+#[test]
+fn blank_lines_have_results() {
+    let batch = BatchParser::new().parse(b"\n\r\n");
+    let stats = batch.stats();
+    assert_eq!(stats.lines, 2);
+    assert_eq!(stats.framing_errors, 2);
+}
+
+#[test]
+fn for_each_matches_parse() {
+    let log = log(500, "\r\n");
+    let parser = BatchParser::new().chunk_size(300);
+    let batch = parser.parse(log.as_bytes());
+    let (results, stats) = for_each_results(parser, log.as_bytes());
+
+    assert_eq!(stats, batch.stats());
+    assert!(results
+        .iter()
+        .map(|(line_number, _)| *line_number)
+        .eq(1..=500));
+    assert!(results.iter().map(|(_, result)| result).eq(batch.results()));
+}
+
+#[test]
+fn for_each_keeps_line_order_within_chunks() {
+    let log = log(200, "\n");
+    let order = Mutex::new(Vec::new());
+    BatchParser::new()
+        .chunk_size(128)
+        .for_each(log.as_bytes(), |line_number, _| {
+            order.lock().unwrap().push(line_number)
+        });
+    let order = order.into_inner().unwrap();
+    let batch = BatchParser::new().chunk_size(128).parse(log.as_bytes());
+    for chunk in &batch.chunks {
+        let lines: Vec<usize> = order
+            .iter()
+            .copied()
+            .filter(|line| {
+                (chunk.first_line..chunk.first_line + chunk.results.len()).contains(line)
+            })
+            .collect();
+        assert!(lines
+            .iter()
+            .copied()
+            .eq(chunk.first_line..chunk.first_line + chunk.results.len()));
+    }
+}
+
+#[test]
+fn files_are_parsed_like_bytes() {
+    let log = log(300, "\r\n");
+    let path = std::env::temp_dir().join(format!("rust_nmea_batch_{}.nmea", std::process::id()));
+    fs::write(&path, &log).unwrap();
+
+    let parser = BatchParser::new().chunk_size(512);
+    let batch = parser.parse_file(&path).unwrap();
+    let (results, stats) = {
+        let results = Mutex::new(0);
+        let stats = parser
+            .for_each_file(&path, |_, _| *results.lock().unwrap() += 1)
+            .unwrap();
+        (results.into_inner().unwrap(), stats)
+    };
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(batch, parser.parse(log.as_bytes()));
+    assert_eq!(results, 300);
+    assert_eq!(stats, batch.stats());
+}
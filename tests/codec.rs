@@ -0,0 +1,154 @@
+//! Framing sentences on async streams with `NmeaCodec`
+#![cfg(feature = "tokio")]
+
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::{SinkExt, StreamExt};
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio_util::codec::{FramedRead, FramedWrite};
+
+use rust_nmea::{
+    codec::NmeaCodec,
+    commands::{hdt::HDT, zda::ZDA},
+    parser::Parser,
+    types::{CommandTypes, ErrorKind, TalkerIds},
+};
+
+const ZDA_LINE: &str = "$GPZDA,023012.00,01,03,2024,-05,00*4A\r\n";
+const DPT_LINE: &str = "$SDDPT,2.4,0.5*54\r\n";
+
+/// Source returning one chunk per read, pending once between chunks like a slow serial port
+struct Chunked {
+    chunks: VecDeque<Vec<u8>>,
+    pending: bool,
+}
+
+impl Chunked {
+    fn new<'a>(chunks: impl IntoIterator<Item = &'a [u8]>) -> Chunked {
+        Chunked {
+            chunks: chunks.into_iter().map(<[u8]>::to_vec).collect(),
+            pending: false,
+        }
+    }
+}
+
+impl AsyncRead for Chunked {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.pending = !self.pending;
+        if self.pending {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        if let Some(mut chunk) = self.chunks.pop_front() {
+            let read = chunk.len().min(buf.remaining());
+            buf.put_slice(&chunk[..read]);
+            if read < chunk.len() {
+                self.chunks.push_front(chunk.split_off(read));
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+async fn sentence_ids(source: Chunked) -> Vec<Result<String, ErrorKind>> {
+    FramedRead::new(source, NmeaCodec::new())
+        .map(|item| match item.unwrap() {
+            Ok(sentence) => Ok(sentence.sentence_id.to_string()),
+            Err(e) => Err(e.kind),
+        })
+        .collect()
+        .await
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn decodes_sentences_split_across_reads() {
+    let stream = format!("{}{}", ZDA_LINE, DPT_LINE);
+    let bytes = stream.as_bytes();
+    for split in 1..bytes.len() {
+        let source = Chunked::new([&bytes[..split], &bytes[split..]]);
+        assert_eq!(
+            sentence_ids(source).await,
+            vec![Ok("ZDA".to_string()), Ok("DPT".to_string())],
+            "split at {}",
+            split
+        );
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn decodes_byte_by_byte() {
+    let source = Chunked::new(ZDA_LINE.as_bytes().chunks(1));
+    assert_eq!(sentence_ids(source).await, vec![Ok("ZDA".to_string())]);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn decodes_sentence_without_line_ending_at_end_of_stream() {
+    let source = Chunked::new([ZDA_LINE.as_bytes(), DPT_LINE.trim_end().as_bytes()]);
+    assert_eq!(
+        sentence_ids(source).await,
+        vec![Ok("ZDA".to_string()), Ok("DPT".to_string())]
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn failing_sentence_does_not_end_the_stream() {
+    let source = Chunked::new([
+        b"$GPZDA,broken*00\r\n$SDDP".as_slice(),
+        b"T,2.4,0.5*54\r\n".as_slice(),
+    ]);
+    let ids = sentence_ids(source).await;
+    assert_eq!(ids.len(), 2);
+    assert!(ids[0].is_err());
+    assert_eq!(ids[1], Ok("DPT".to_string()));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn encodes_sentences_as_checksummed_lines() {
+    let mut sink = FramedWrite::new(Vec::new(), NmeaCodec::new().talker_id(TalkerIds::HE));
+    sink.send(HDT {
+        heading: Some(274.07),
+    })
+    .await
+    .unwrap();
+    sink.send(DPT_LINE).await.unwrap();
+    sink.send(ZDA_LINE.trim_end().to_string()).await.unwrap();
+
+    let written = String::from_utf8(sink.into_inner()).unwrap();
+    assert_eq!(
+        written,
+        format!("$HEHDT,274.07,T*19\r\n{}{}", DPT_LINE, ZDA_LINE)
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn encoded_sentences_decode_back() {
+    let zda = match Parser::parse_line(ZDA_LINE.trim_end()).unwrap() {
+        CommandTypes::ZDA(zda) => zda,
+        other => panic!("expected ZDA, got {:?}", other),
+    };
+    let east = ZDA {
+        local_zone_hours: 5,
+        local_zone_minutes: 30,
+        ..zda.clone()
+    };
+    let mut sink = FramedWrite::new(Vec::new(), NmeaCodec::new());
+    sink.send(zda.clone()).await.unwrap();
+    sink.send(east.clone()).await.unwrap();
+    let written = sink.into_inner();
+
+    let decoded: Vec<_> = FramedRead::new(Chunked::new(written.chunks(7)), NmeaCodec::new())
+        .map(|item| item.unwrap().unwrap())
+        .collect()
+        .await;
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded[0].talker_id, TalkerIds::GP);
+    assert_eq!(decoded[0].body, CommandTypes::ZDA(zda));
+    assert_eq!(decoded[1].body, CommandTypes::ZDA(east));
+}
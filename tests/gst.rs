@@ -0,0 +1,71 @@
+//! Error ellipse of GST against hand-computed values
+#![cfg(feature = "std")]
+
+use rust_nmea::commands::gst::GST;
+
+fn ellipse(semi_major: f64, semi_minor: f64, orientation: f64) -> GST {
+    GST {
+        semi_major: Some(semi_major),
+        semi_minor: Some(semi_minor),
+        orientation: Some(orientation),
+        ..Default::default()
+    }
+}
+
+fn assert_close(matrix: [[f64; 2]; 2], expected: [[f64; 2]; 2]) {
+    for (row, expected_row) in matrix.iter().zip(&expected) {
+        for (value, expected) in row.iter().zip(expected_row) {
+            assert!(
+                (value - expected).abs() < 1e-12,
+                "{:?} {:?}",
+                matrix,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn covariance_of_rotated_ellipse() {
+    // Semi-major axis of 2 m at 30° from north and semi-minor axis of 1 m:
+    // nn = 4 cos²30 + 1 sin²30 = 3.25, ee = 4 sin²30 + 1 cos²30 = 1.75, ne = (4 - 1) sin30 cos30 = 3√3/4
+    let ne = 3.0 * 3f64.sqrt() / 4.0;
+    assert_close(
+        ellipse(2.0, 1.0, 30.0).covariance_matrix().unwrap(),
+        [[3.25, ne], [ne, 1.75]],
+    );
+}
+
+#[test]
+fn covariance_of_axis_aligned_ellipses() {
+    assert_close(
+        ellipse(2.0, 1.0, 0.0).covariance_matrix().unwrap(),
+        [[4.0, 0.0], [0.0, 1.0]],
+    );
+    assert_close(
+        ellipse(2.0, 1.0, 90.0).covariance_matrix().unwrap(),
+        [[1.0, 0.0], [0.0, 4.0]],
+    );
+    // A half turn describes the same ellipse
+    assert_close(
+        ellipse(2.0, 1.0, 210.0).covariance_matrix().unwrap(),
+        ellipse(2.0, 1.0, 30.0).covariance_matrix().unwrap(),
+    );
+}
+
+#[test]
+fn covariance_keeps_axis_variances() {
+    // Trace and determinant are the sum and product of the axis variances for any orientation
+    for orientation in [0.0, 17.5, 45.0, 123.0, 359.0] {
+        let [[nn, ne], [en, ee]] = ellipse(0.9, 0.6, orientation).covariance_matrix().unwrap();
+        assert!((nn + ee - (0.81 + 0.36)).abs() < 1e-12);
+        assert!((nn * ee - ne * en - 0.81 * 0.36).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn covariance_needs_every_ellipse_field() {
+    let mut gst = ellipse(2.0, 1.0, 30.0);
+    gst.orientation = None;
+    assert_eq!(gst.covariance_matrix(), None);
+}
@@ -0,0 +1,287 @@
+//! Malformed fields are reported as errors naming the field, empty fields are `None`
+
+use rust_nmea::{
+    parser::Parser,
+    types::{CommandTypes, Error, ErrorKind},
+};
+
+fn parse(body: &str) -> Result<CommandTypes, Error> {
+    let checksum = body.bytes().fold(0, |checksum, byte| checksum ^ byte);
+    Parser::parse_line(&format!("${}*{:02X}", body, checksum))
+}
+
+/// Sentence body, failing field index and field name
+const BAD_NUMBERS: &[(&str, usize, &str)] = &[
+    (
+        "GPGST,082356.00,1.8,x,0.6,45.0,0.8,0.7,1.4",
+        2,
+        "semi_major",
+    ),
+    (
+        "GPGST,082356.00,1.8,0.9,0.6,45.0,0.8,0.7,1.4.",
+        7,
+        "altitude_error",
+    ),
+    ("HCHDG,98.3,0.0,E,1 2.6,W", 3, "variation"),
+    ("HCHDG,abc,0.0,E,12.6,W", 0, "heading"),
+    ("HCHDM,271.2M,M", 0, "heading"),
+    ("HEHDT,abc,T", 0, "heading"),
+    ("GPTHS,77.52T,A", 0, "heading"),
+    ("HEROT,--0.3,A", 0, "rate_of_turn"),
+    ("VWVLW,1.0.0,N,,", 0, "total_water_distance"),
+    ("VWVLW,,,,,1.00,N,1:00,N", 6, "trip_ground_distance"),
+    ("GPGRS,082356.00,1,0.5,-12.8,x,,,,,,,,,", 4, "residual"),
+    (
+        "GNGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10;5,,,0.0,",
+        7,
+        "horizontal_dilution_of_precision",
+    ),
+    (
+        "GNGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10.5,1O,,0.0,",
+        8,
+        "orthometric_height",
+    ),
+    (
+        "GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,x,E,A",
+        9,
+        "magnetic_variation",
+    ),
+    (
+        "GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.5.2,091202,,,A",
+        7,
+        "course_over_ground",
+    ),
+    (
+        "GPVTG,77.52,T,,M,0.004,N,O.008,K,A",
+        6,
+        "speed_over_ground_second",
+    ),
+    (
+        "GPVTG,x,T,,M,0.004,N,0.008,K,A",
+        0,
+        "course_over_ground_true",
+    ),
+    ("GPGSA,A,3,05,I2,25,,,,,,,,,,2.1,1.2,1.7", 3, "satellite_id"),
+    (
+        "GNGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10.5,,,0.0,ABC",
+        11,
+        "reference_station_id",
+    ),
+    (
+        "GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,NaN,11.5,M,11.3,M,,",
+        7,
+        "horizontal_dilution_of_position",
+    ),
+    (
+        "GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,inf,11.5,M,11.3,M,,",
+        7,
+        "horizontal_dilution_of_position",
+    ),
+    (
+        "GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,1e400,M,11.3,M,,",
+        8,
+        "altitude",
+    ),
+    (
+        "GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,1.15e1,M,11.3,M,,",
+        8,
+        "altitude",
+    ),
+    ("GPZDA,+1+2+3.00,01,03,2024,-05,00", 0, "time"),
+    ("GPZDA,01-203.00,01,03,2024,-05,00", 0, "time"),
+    (
+        "GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,+1+2+3,,,A",
+        8,
+        "date",
+    ),
+    ("GPGSV,3,3,10,31,43,29x,41,32,02,186,", 5, "azimuth"),
+    ("GPGSV,3,3,10,31,43,292,41,32,02,186,-", 10, "snr"),
+];
+
+/// Sentence body, failing field index and field name
+const BAD_CODES: &[(&str, usize, &str)] = &[
+    (
+        "GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,1.2,N,A",
+        10,
+        "magnetic_variation_indicator",
+    ),
+    (
+        "GPVTG,77.52,M,,M,0.004,N,0.008,K,A",
+        1,
+        "course_over_ground_unit",
+    ),
+    (
+        "GPVTG,77.52,T,,M,0.004,X,0.008,K,A",
+        5,
+        "speed_over_ground_first_unit",
+    ),
+    (
+        "GNGNS,112257.00,3844.24011,N,00908.43828,W,AX,03,10.5,,,0.0,",
+        5,
+        "mode_indicator",
+    ),
+    ("HCHDG,98.3,0.0,EX,12.6,W", 2, "deviation_direction"),
+    ("HCHDG,98.3,0.0,E,12.6,WEST", 4, "variation_direction"),
+    ("HCHDG,98.3,0.0,N,12.6,W", 2, "deviation_direction"),
+    (
+        "GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,1.2,EX,A",
+        10,
+        "magnetic_variation_indicator",
+    ),
+    (
+        "GPGGA,161009.00,1122.20418,NORTH,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,",
+        2,
+        "northing_indicator",
+    ),
+    ("VWVLW,1.0,Q,2.0,N,,,,", 1, "total_water_distance_unit"),
+    ("VWVLW,1.0,N,2.0,FOO,,,,", 3, "trip_water_distance_unit"),
+    ("VWVLW,,,,,1.0,NM,2.0,N", 5, "total_ground_distance_unit"),
+    ("VWVLW,,,,,1.0,N,2.0,K", 7, "trip_ground_distance_unit"),
+    ("GBGSV,1,1,01,05,38,039,31,G", 7, "signal_id"),
+    ("GPGRS,082356.00,1,0.5,,,,,,,,,,,,1,1G", 15, "signal_id"),
+    (
+        "GNGNS,112257.00,3844.24011,X,00908.43828,W,AN,03,10.5,,,0.0,",
+        2,
+        "latitude_direction",
+    ),
+];
+
+/// Sentence body, failing field index and field name
+const BAD_CORDINATES: &[(&str, ErrorKind, usize, &str)] = &[
+    (
+        "GPGLL,4760.00000,N,00833.91522,E,083559.00,A,A",
+        ErrorKind::OutOfRange,
+        0,
+        "latitude",
+    ),
+    (
+        "GPGLL,9000.00001,N,00833.91522,E,083559.00,A,A",
+        ErrorKind::OutOfRange,
+        0,
+        "latitude",
+    ),
+    (
+        "GPGLL,4717.11437,N,18000.00100,E,083559.00,A,A",
+        ErrorKind::OutOfRange,
+        2,
+        "longitude",
+    ),
+    (
+        "GPGGA,161009.00,1122.5e9,N,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,",
+        ErrorKind::InvalidNumber,
+        1,
+        "latitude",
+    ),
+    (
+        "GPRMC,083559.00,A,4717.11437,N,-0833.91522,E,0.004,77.52,091202,,,A",
+        ErrorKind::InvalidNumber,
+        4,
+        "longitude",
+    ),
+    (
+        "GNGNS,112257.00,3844.24011,N,00975.43828,W,AN,03,10.5,,,0.0,",
+        ErrorKind::OutOfRange,
+        3,
+        "longitude",
+    ),
+    (
+        "GNGNS,112257.00,38442.4011,N,00908.43828,W,AN,03,10.5,,,0.0,",
+        ErrorKind::OutOfRange,
+        1,
+        "latitude",
+    ),
+];
+
+#[test]
+fn bad_cordinates_are_errors() {
+    for (body, kind, index, name) in BAD_CORDINATES {
+        let error = parse(body).expect_err(body);
+        assert_eq!(error.kind, *kind, "{}", body);
+        let field = error.field.as_ref().expect(body);
+        assert_eq!((field.index, field.name), (*index, *name), "{}", body);
+    }
+    let limits = parse("GPGLL,9000.00000,N,18000.00000,W,083559.00,A,A");
+    assert!(matches!(limits, Ok(CommandTypes::GLL(_))), "{:?}", limits);
+}
+
+#[test]
+fn bad_codes_are_invalid_code_errors() {
+    for (body, index, name) in BAD_CODES {
+        let error = parse(body).expect_err(body);
+        assert_eq!(error.kind, ErrorKind::InvalidCode, "{}", body);
+        let field = error.field.as_ref().expect(body);
+        assert_eq!((field.index, field.name), (*index, *name), "{}", body);
+    }
+}
+
+#[test]
+fn bad_numbers_are_invalid_number_errors() {
+    for (body, index, name) in BAD_NUMBERS {
+        let error = parse(body).expect_err(body);
+        assert_eq!(error.kind, ErrorKind::InvalidNumber, "{}", body);
+        let field = error.field.as_ref().expect(body);
+        assert_eq!((field.index, field.name), (*index, *name), "{}", body);
+    }
+}
+
+#[test]
+fn empty_numbers_are_none() {
+    assert!(matches!(
+        parse("HEHDT,,T"),
+        Ok(CommandTypes::HDT(hdt)) if hdt.heading.is_none()
+    ));
+    assert!(matches!(
+        parse("HEROT,,V"),
+        Ok(CommandTypes::ROT(rot)) if rot.rate_of_turn.is_none()
+    ));
+    assert!(matches!(
+        parse("GPVTG,,,,,,,,,N"),
+        Ok(CommandTypes::VTG(vtg)) if vtg.course_over_ground_unit.is_none()
+            && vtg.speed_over_ground_first.is_none()
+            && vtg.speed_over_ground_second_unit.is_none()
+    ));
+    assert!(matches!(
+        parse("GPGGA,,,,,,0,00,99.99,,,,,,"),
+        Ok(CommandTypes::GGA(gga)) if gga.altitude_unit.is_none()
+            && gga.geoid_separation_unit.is_none()
+            && gga.horizontal_dilution_of_position == Some(99.99)
+    ));
+    assert!(matches!(
+        parse("GNGNS,112257.00,,,,,NNN,00,99.99,,,,"),
+        Ok(CommandTypes::GNS(gns)) if gns.latitude_direction.is_none()
+            && gns.reference_station_id.is_none()
+            && gns.beidou_mode_indicator.is_none()
+    ));
+    assert!(matches!(
+        parse("GBGSV,1,1,01,05,38,,,B"),
+        Ok(CommandTypes::GSV(gsv)) if gsv.signal_id == Some(11)
+            && gsv.satellites[0].azimuth.is_none()
+            && gsv.satellites[1].id.is_none()
+    ));
+    assert!(matches!(
+        parse("GBGRS,082356.00,1,0.5,,,,,,,,,,,,4,B"),
+        Ok(CommandTypes::GRS(grs)) if grs.system_id == Some(4) && grs.signal_id == Some(11)
+    ));
+    assert!(matches!(
+        parse("GPGST,082356.00,,,,,,,"),
+        Ok(CommandTypes::GST(gst)) if gst.rms.is_none() && gst.altitude_error.is_none()
+    ));
+}
+
+#[test]
+fn bad_residual_does_not_shift_gsa_positions() {
+    let error = parse("GPGRS,082356.00,1,0.5,1.5e,-12.8,,,,,,,,,").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::InvalidNumber);
+    assert_eq!(error.field.map(|field| field.index), Some(3));
+
+    let (Ok(CommandTypes::GSA(gsa)), Ok(CommandTypes::GRS(grs))) = (
+        parse("GPGSA,A,3,05,12,25,,,,,,,,,,2.1,1.2,1.7"),
+        parse("GPGRS,082356.00,1,0.5,,-12.8,,,,,,,,,"),
+    ) else {
+        panic!("Expected GSA and GRS");
+    };
+    assert_eq!(
+        grs.satellite_residuals(&gsa).collect::<Vec<_>>(),
+        [(5, 0.5), (25, -12.8)]
+    );
+}
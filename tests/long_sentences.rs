@@ -0,0 +1,75 @@
+//! Sentences kept as text may be longer than the 82 characters of the standard
+
+use rust_nmea::{
+    parser::Parser,
+    types::{CommandTypes, CommandTypesRef, Error, SentenceData},
+};
+
+fn with_checksum(body: &str) -> String {
+    let checksum = body.bytes().fold(0, |checksum, byte| checksum ^ byte);
+    format!("${}*{:02X}", body, checksum)
+}
+
+/// Transducer measurements of `count` sensors
+fn xdr(count: usize) -> String {
+    let mut body = String::from("IIXDR");
+    for sensor in 0..count {
+        body.push_str(&format!(",C,19.5,C,AIR_TEMPERATURE_{:02}", sensor));
+    }
+    body
+}
+
+/// Version report of a receiver listing its firmware libraries
+fn version_report() -> String {
+    let mut body = String::from("PSTMVER");
+    for library in [
+        "GNSSLIB", "OS20LIB", "GPSAPP", "BINIMG", "SWCFG", "PROTOCOL", "DRIVERS",
+    ] {
+        body.push_str(&format!(",{}_8.4.18.25_CP_ARM_RELEASE_BUILD", library));
+    }
+    body
+}
+
+fn parse(body: &str) -> Result<SentenceData, Error> {
+    match Parser::parse_line(&with_checksum(body))? {
+        CommandTypes::Proprietary(proprietary) => Ok(proprietary.data),
+        CommandTypes::Unknown(unknown) => Ok(unknown.data),
+        other => panic!("Expected sentence kept as text, got {:?}", other),
+    }
+}
+
+#[test]
+fn borrowed_data_has_no_length_limit() {
+    let line = with_checksum(&version_report());
+    let sentence = Parser::parse_borrowed(&line).unwrap();
+    let CommandTypesRef::Proprietary(proprietary) = sentence.body else {
+        panic!("Expected proprietary sentence");
+    };
+    assert!(proprietary.data.len() > 256, "{}", proprietary.data.len());
+    assert_eq!(proprietary.fields().count(), 7);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned_data_has_no_length_limit() {
+    let body = version_report();
+    assert_eq!(parse(&body).unwrap(), body["PSTMVER,".len()..]);
+
+    let body = xdr(12);
+    assert!(body.len() > 256, "{}", body.len());
+    assert_eq!(parse(&body).unwrap(), body["IIXDR,".len()..]);
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn owned_data_is_limited_without_alloc() {
+    use rust_nmea::types::ErrorKind;
+
+    let error = parse(&version_report()).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::TooLong);
+    assert_eq!(error.sentence_id, "STMVER");
+
+    let body = xdr(8);
+    assert_eq!(parse(&body).unwrap(), body["IIXDR,".len()..]);
+    assert_eq!(parse(&xdr(12)).unwrap_err().kind, ErrorKind::TooLong);
+}
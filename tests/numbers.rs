@@ -0,0 +1,95 @@
+//! Number fields are parsed exactly like std parses plain decimals
+
+use proptest::prelude::*;
+use rust_nmea::{
+    parser::Parser,
+    types::{CommandTypes, Error, ErrorKind},
+};
+
+fn parse(body: &str) -> Result<CommandTypes, Error> {
+    let checksum = body.bytes().fold(0, |checksum, byte| checksum ^ byte);
+    Parser::parse_line(&format!("${}*{:02X}", body, checksum))
+}
+
+fn heading(number: &str) -> Result<Option<f64>, ErrorKind> {
+    match parse(&format!("HEHDT,{},T", number)) {
+        Ok(CommandTypes::HDT(hdt)) => Ok(hdt.heading),
+        Ok(other) => panic!("Expected HDT, got {:?}", other),
+        Err(e) => Err(e.kind),
+    }
+}
+
+fn differential_age(number: &str) -> Result<Option<f32>, ErrorKind> {
+    let body = format!(
+        "GPGGA,161009.00,1122.20418,N,02339.35234,E,2,08,1.09,11.5,M,11.3,M,{},0120",
+        number
+    );
+    match parse(&body) {
+        Ok(CommandTypes::GGA(gga)) => Ok(gga.differential_age_of_position),
+        Ok(other) => panic!("Expected GGA, got {:?}", other),
+        Err(e) => Err(e.kind),
+    }
+}
+
+fn azimuth(number: &str) -> Result<Option<usize>, ErrorKind> {
+    match parse(&format!("GPGSV,1,1,01,05,38,{},31", number)) {
+        Ok(CommandTypes::GSV(gsv)) => Ok(gsv.satellites[0].azimuth),
+        Ok(other) => panic!("Expected GSV, got {:?}", other),
+        Err(e) => Err(e.kind),
+    }
+}
+
+#[test]
+fn edge_cases() {
+    assert_eq!(
+        heading("-0.0").unwrap().map(f64::is_sign_negative),
+        Some(true)
+    );
+    assert_eq!(heading(".5"), Ok(Some(0.5)));
+    assert_eq!(heading("5."), Ok(Some(5.0)));
+    assert_eq!(heading("+5"), Ok(Some(5.0)));
+    assert_eq!(heading("0.1"), Ok(Some(0.1)));
+    assert_eq!(heading("9007199254740993"), Ok(Some(9007199254740992.0)));
+    assert_eq!(heading("1.00000000000000000000001"), Ok(Some(1.0)));
+    assert_eq!(differential_age("0.1"), Ok(Some(0.1)));
+    for invalid in [
+        ".", "-", "+", "1.2.3", "--1", "1-", "1e5", "inf", "NaN", " 1",
+    ] {
+        assert_eq!(
+            heading(invalid),
+            Err(ErrorKind::InvalidNumber),
+            "{}",
+            invalid
+        );
+        assert_eq!(
+            differential_age(invalid),
+            Err(ErrorKind::InvalidNumber),
+            "{}",
+            invalid
+        );
+    }
+    assert_eq!(azimuth("+7"), Ok(Some(7)));
+    assert_eq!(azimuth("-0"), Err(ErrorKind::InvalidNumber));
+    assert_eq!(
+        azimuth("18446744073709551616"),
+        Err(ErrorKind::InvalidNumber)
+    );
+}
+
+proptest! {
+    #[test]
+    fn floats_match_std(number in "[-+]?[0-9]{0,20}(\\.[0-9]{0,25})?") {
+        // Empty fields are `None`, see `invalid_fields`
+        prop_assume!(!number.is_empty());
+        let std64 = number.parse::<f64>().map_err(|_| ErrorKind::InvalidNumber);
+        prop_assert_eq!(heading(&number), std64.map(Some));
+        let std32 = number.parse::<f32>().map_err(|_| ErrorKind::InvalidNumber);
+        prop_assert_eq!(differential_age(&number), std32.map(Some));
+    }
+
+    #[test]
+    fn integers_match_std(number in "[-+]?[0-9]{1,22}") {
+        let std = number.parse::<usize>().map_err(|_| ErrorKind::InvalidNumber);
+        prop_assert_eq!(azimuth(&number), std.map(Some));
+    }
+}
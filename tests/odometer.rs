@@ -0,0 +1,58 @@
+//! Distance log accumulated by `Odometer`
+#![cfg(feature = "std")]
+
+use rust_nmea::commands::vlw::Odometer;
+
+/// One meter in degrees of latitude
+const METER: f64 = 1.0 / 111_120.0;
+
+#[test]
+fn stationary_jitter_is_not_counted() {
+    let mut odometer = Odometer::default();
+    for step in 0..1000 {
+        let offset = if step % 2 == 0 { 3.0 } else { -3.0 };
+        odometer.push_position(47.0 + offset * METER, 8.0);
+    }
+    assert_eq!(odometer.total_distance(), 0.0);
+
+    odometer.push_position(48.0, 8.0);
+    assert!((odometer.total_distance() - 60.0).abs() < 0.1);
+}
+
+#[test]
+fn slow_drift_is_counted_once_past_threshold() {
+    let mut odometer = Odometer::default();
+    for step in 0..=20 {
+        odometer.push_position(47.0 + step as f64 * METER, 8.0);
+    }
+    // 20 meters drifted one meter at a time, counted in 10 meter steps
+    let meters = odometer.total_distance() * 1852.0;
+    assert!((meters - 20.0).abs() < 0.1, "{}", meters);
+}
+
+#[test]
+fn zero_threshold_counts_every_change() {
+    let mut odometer = Odometer::default().min_displacement(0.0);
+    for step in 0..1000 {
+        let offset = if step % 2 == 0 { 3.0 } else { -3.0 };
+        odometer.push_position(47.0 + offset * METER, 8.0);
+    }
+    let meters = odometer.total_distance() * 1852.0;
+    assert!((meters - 999.0 * 6.0).abs() < 10.0, "{}", meters);
+}
+
+#[test]
+fn water_distances_mirror_ground_distances() {
+    let mut odometer = Odometer::with_total_distance(10.0);
+    odometer.push_position(47.0, 8.0);
+    odometer.push_position(47.5, 8.0);
+    let vlw = odometer.vlw();
+    assert_eq!(vlw.total_water_distance, vlw.total_ground_distance);
+    assert_eq!(vlw.trip_water_distance, vlw.trip_ground_distance);
+    assert_eq!(vlw.total_water_distance_unit, Some('N'));
+    assert_eq!(vlw.trip_water_distance_unit, Some('N'));
+
+    let vlw = odometer.clone().water_distances(false).vlw();
+    assert!(vlw.total_water_distance.is_none() && vlw.trip_water_distance_unit.is_none());
+    assert!(vlw.total_ground_distance.is_some());
+}
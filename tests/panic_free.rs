@@ -0,0 +1,135 @@
+//! Malformed, truncated and random input must never make the parser panic
+
+use proptest::prelude::*;
+use rust_nmea::{parser::Parser, stream::StreamDecoder};
+
+/// One sentence of every supported type, without `$` and checksum
+const SAMPLES: &[&str] = &[
+    "GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,",
+    "GLGSV,1,1,02,65,38,039,31,66,71,324,29,1",
+    "GPGLL,4717.11437,N,00833.91522,E,083559.00,A,A",
+    "GPGSA,A,3,05,12,25,,,,,,,,,,2.1,1.2,1.7",
+    "GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A",
+    "GPVTG,77.52,T,,M,0.004,N,0.008,K,A",
+    "GPDTM,W84,,0.0,N,0.0,E,0.0,W84",
+    "GPGBS,235458.00,1.4,1.3,3.1,03,0.0,-21.4,3.8",
+    "GNGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10.5,,,0.0,",
+    "GPZDA,023012.00,01,03,2024,-05,00",
+    "GPGST,082356.00,1.8,0.9,0.6,45.0,0.8,0.7,1.4",
+    "GPGRS,082356.00,1,0.5,-12.8,,,,,,,,,,",
+    "GPTXT,01,01,02,ANTSTATUS=OK",
+    "GPTHS,77.52,A",
+    "HEHDT,274.07,T",
+    "HCHDG,98.3,0.0,E,12.6,W",
+    "HCHDM,271.2,M",
+    "HEROT,-0.3,A",
+    "VWVLW,,,,,1.00,N,1.00,N",
+    "GARLM,9C6AE2A50BFBFF0,083559.00,1,8000",
+    "PGRME,15.0,M,45.0,M,25.0,M",
+    "SDDPT,2.4,0.5",
+];
+
+fn with_checksum(body: &str) -> String {
+    let checksum = body.bytes().fold(0, |checksum, byte| checksum ^ byte);
+    format!("${}*{:02X}", body, checksum)
+}
+
+fn parse_everywhere(line: &str) {
+    let _ = Parser::parse_line(line);
+    let _ = Parser::parse_borrowed(line).map(|sentence| sentence.into_owned());
+    let _ = Parser::parse_bytes(line.as_bytes());
+}
+
+#[test]
+fn samples_parse() {
+    for sample in SAMPLES {
+        let line = with_checksum(sample);
+        let parsed = Parser::parse_line(&line);
+        assert!(parsed.is_ok(), "{} {:?}", line, parsed);
+    }
+}
+
+#[test]
+fn truncated_fields() {
+    for sample in SAMPLES {
+        let fields: Vec<&str> = sample.split(',').collect();
+        for count in 0..=fields.len() {
+            parse_everywhere(&with_checksum(&fields[..count].join(",")));
+        }
+    }
+}
+
+#[test]
+fn truncated_lines() {
+    for sample in SAMPLES {
+        let line = with_checksum(sample);
+        for end in 0..=line.len() {
+            parse_everywhere(&line[..end]);
+            parse_everywhere(&with_checksum(&sample[..end.min(sample.len())]));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn random_lines(line in ".*") {
+        parse_everywhere(&line);
+    }
+
+    #[test]
+    fn random_bodies(body in "[A-Z]{5}(,[ -~]{0,12}){0,24}") {
+        parse_everywhere(&with_checksum(&body));
+    }
+
+    #[test]
+    fn random_fields(
+        sample in prop::sample::select(SAMPLES),
+        replacements in prop::collection::vec((0usize..24, "[ -~]{0,16}"), 1..4),
+        count in 0usize..24,
+    ) {
+        let mut fields: Vec<String> = sample.split(',').map(String::from).collect();
+        for (index, replacement) in replacements {
+            if index > 0 && index < fields.len() {
+                fields[index] = replacement;
+            }
+        }
+        fields.truncate(count.max(1));
+        parse_everywhere(&with_checksum(&fields.join(",")));
+    }
+
+    #[test]
+    fn random_numbers(
+        sample in prop::sample::select(SAMPLES),
+        index in 1usize..24,
+        number in prop_oneof![
+            any::<i64>().prop_map(|e| e.to_string()),
+            any::<f64>().prop_map(|e| e.to_string()),
+            "[0-9]{1,30}",
+            "-?[0-9]{0,8}\\.[0-9]{0,8}",
+        ],
+    ) {
+        let mut fields: Vec<String> = sample.split(',').map(String::from).collect();
+        if index < fields.len() {
+            fields[index] = number;
+        }
+        let line = with_checksum(&fields.join(","));
+        parse_everywhere(&line);
+        if let Ok(rust_nmea::types::CommandTypes::ZDA(zda)) = Parser::parse_line(&line) {
+            let _ = zda.zoned_date_time().unix_timestamp();
+        }
+    }
+
+    #[test]
+    fn random_bytes(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        let _ = Parser::parse_bytes(&bytes);
+        let mut decoder = StreamDecoder::new();
+        for sentence in decoder.sentences(&bytes) {
+            let _ = sentence;
+        }
+        let _ = decoder.finish();
+        let mut decoder = StreamDecoder::<0>::default();
+        for sentence in decoder.sentences(&bytes) {
+            let _ = sentence;
+        }
+    }
+}
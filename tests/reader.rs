@@ -0,0 +1,252 @@
+//! Reading logs with `NmeaReader`
+#![cfg(feature = "std")]
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+use rust_nmea::{
+    reader::{NmeaReader, ReadError, ReadSentence, RecoveryPolicy},
+    types::{ErrorKind, FramingError},
+};
+
+const LOG: &str = "$GPZDA,023012.00,01,03,2024,-05,00*4A\r\n\
+                   $GPZDA,broken*00\r\n\
+                   $SDDPT,2.4,0.5*54\r\n";
+
+fn line_numbers<R: BufRead>(reader: NmeaReader<R>) -> Vec<Result<usize, usize>> {
+    reader
+        .map(|item| match item {
+            Ok(read) => Ok(read.line_number),
+            Err(ReadError::Parse { line_number, .. }) => Err(line_number),
+            Err(ReadError::Io(e)) => panic!("unexpected read error {}", e),
+        })
+        .collect()
+}
+
+fn framing_error(item: Option<Result<ReadSentence, ReadError>>) -> Option<FramingError> {
+    match item {
+        Some(Err(ReadError::Parse { error, .. })) => match error.kind {
+            ErrorKind::Framing(framing) => Some(framing),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Log file in the temp directory, removed when dropped
+struct TempLog(PathBuf);
+
+impl TempLog {
+    fn new(name: &str, contents: &[u8]) -> TempLog {
+        let path = std::env::temp_dir().join(format!("rust_nmea_{}_{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        TempLog(path)
+    }
+}
+
+impl Drop for TempLog {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn skip_policy_drops_failing_lines() {
+    let reader = NmeaReader::new(LOG.as_bytes()).policy(RecoveryPolicy::Skip);
+    assert_eq!(line_numbers(reader), vec![Ok(1), Ok(3)]);
+}
+
+#[test]
+fn yield_policy_continues_after_failing_lines() {
+    let reader = NmeaReader::new(LOG.as_bytes()).policy(RecoveryPolicy::Yield);
+    assert_eq!(line_numbers(reader), vec![Ok(1), Err(2), Ok(3)]);
+}
+
+#[test]
+fn stop_policy_ends_at_first_failing_line() {
+    let mut reader = NmeaReader::new(LOG.as_bytes()).policy(RecoveryPolicy::Stop);
+    assert!(reader.next().unwrap().is_ok());
+    assert!(matches!(
+        reader.next(),
+        Some(Err(ReadError::Parse {
+            line_number: 2,
+            byte_offset: 39,
+            ..
+        }))
+    ));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn invalid_utf8_is_a_framing_error() {
+    let log =
+        b"$GPZDA,023012.00,01,03,2024,-05,00*4A\n$GPTXT,01,01,02,\xff\xfe*00\n$SDDPT,2.4,0.5*54\n";
+    let mut reader = NmeaReader::new(&log[..]);
+    assert!(reader.next().unwrap().is_ok());
+    assert_eq!(
+        framing_error(reader.next()),
+        Some(FramingError::InvalidUtf8)
+    );
+    assert_eq!(reader.next().unwrap().unwrap().line_number, 3);
+}
+
+#[test]
+fn long_line_is_skipped_up_to_its_line_ending() {
+    let long = format!("${}\n", "A".repeat(10_000));
+    let log = format!("{}$SDDPT,2.4,0.5*54\n", long);
+    let mut reader = NmeaReader::new(log.as_bytes());
+    assert_eq!(framing_error(reader.next()), Some(FramingError::TooLong));
+
+    let read = reader.next().unwrap().unwrap();
+    assert_eq!(read.line_number, 2);
+    assert_eq!(read.byte_offset, long.len() as u64);
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn max_line_length_counts_bytes_without_line_ending() {
+    let line = "$SDDPT,2.4,0.5*54";
+    let log = format!("{}\r\n", line);
+    let reader = NmeaReader::new(log.as_bytes()).max_line_length(line.len());
+    assert_eq!(line_numbers(reader), vec![Ok(1)]);
+
+    let mut reader = NmeaReader::new(log.as_bytes()).max_line_length(line.len() - 1);
+    assert_eq!(framing_error(reader.next()), Some(FramingError::TooLong));
+    assert!(reader.next().is_none());
+}
+
+/// Source failing after its first line
+struct FailingSource(&'static [u8]);
+
+impl Read for FailingSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::BrokenPipe, "gone"));
+        }
+        let read = self.0.len().min(buf.len());
+        buf[..read].copy_from_slice(&self.0[..read]);
+        self.0 = &self.0[read..];
+        Ok(read)
+    }
+}
+
+#[test]
+fn io_error_ends_iteration() {
+    let source = BufReader::new(FailingSource(b"$SDDPT,2.4,0.5*54\n"));
+    let mut reader = NmeaReader::new(source);
+    assert!(reader.next().unwrap().is_ok());
+    match reader.next() {
+        Some(Err(ReadError::Io(e))) => assert_eq!(e.kind(), io::ErrorKind::BrokenPipe),
+        other => panic!("expected read error, got {:?}", other),
+    }
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn open_plain_log() {
+    let log = TempLog::new("plain.nmea", LOG.as_bytes());
+    let reader = NmeaReader::open(&log.0).unwrap();
+    assert_eq!(line_numbers(reader), vec![Ok(1), Err(2), Ok(3)]);
+}
+
+#[test]
+fn open_missing_file_fails() {
+    let path = std::env::temp_dir().join("rust_nmea_missing.nmea");
+    let error = NmeaReader::open(path).map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::NotFound);
+}
+
+#[cfg(feature = "gzip")]
+fn gzip(data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzip_log() {
+    let data = gzip(LOG.as_bytes());
+    let reader = NmeaReader::from_gzip(&data[..]);
+    assert_eq!(line_numbers(reader), vec![Ok(1), Err(2), Ok(3)]);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzip_members_are_concatenated() {
+    let (first, second) = LOG.split_at(39);
+    let mut members = gzip(first.as_bytes());
+    members.extend(gzip(second.as_bytes()));
+    let reader = NmeaReader::from_gzip(&members[..]);
+    assert_eq!(line_numbers(reader), vec![Ok(1), Err(2), Ok(3)]);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn open_detects_gzip() {
+    let log = TempLog::new("log.nmea.gz", &gzip(LOG.as_bytes()));
+    let reader = NmeaReader::open(&log.0).unwrap();
+    assert_eq!(line_numbers(reader), vec![Ok(1), Err(2), Ok(3)]);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn corrupt_gzip_is_a_read_error() {
+    let mut data = gzip(LOG.as_bytes());
+    data.truncate(data.len() / 2);
+    let errors = NmeaReader::from_gzip(&data[..])
+        .filter(|item| matches!(item, Err(ReadError::Io(_))))
+        .count();
+    assert_eq!(errors, 1);
+}
+
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn open_gzip_needs_feature() {
+    let log = TempLog::new("log.nmea.gz", &[0x1f, 0x8b, 0x08, 0x00]);
+    let error = NmeaReader::open(&log.0).map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+}
+
+#[cfg(feature = "zstd")]
+fn zstd(data: &[u8]) -> Vec<u8> {
+    zstd::encode_all(data, 0).unwrap()
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_log() {
+    let data = zstd(LOG.as_bytes());
+    let reader = NmeaReader::from_zstd(&data[..]).unwrap();
+    assert_eq!(line_numbers(reader), vec![Ok(1), Err(2), Ok(3)]);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn open_detects_zstd() {
+    let log = TempLog::new("log.nmea.zst", &zstd(LOG.as_bytes()));
+    let reader = NmeaReader::open(&log.0).unwrap();
+    assert_eq!(line_numbers(reader), vec![Ok(1), Err(2), Ok(3)]);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn corrupt_zstd_is_a_read_error() {
+    let mut data = zstd(LOG.as_bytes());
+    data.truncate(data.len() / 2);
+    let errors = NmeaReader::from_zstd(&data[..])
+        .unwrap()
+        .filter(|item| matches!(item, Err(ReadError::Io(_))))
+        .count();
+    assert_eq!(errors, 1);
+}
+
+#[cfg(not(feature = "zstd"))]
+#[test]
+fn open_zstd_needs_feature() {
+    let log = TempLog::new("log.nmea.zst", &[0x28, 0xb5, 0x2f, 0xfd]);
+    let error = NmeaReader::open(&log.0).map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+}
@@ -0,0 +1,414 @@
+//! Parsing an encoded sentence must give the encoded sentence back
+#![cfg(feature = "alloc")]
+
+use proptest::prelude::*;
+use rust_nmea::{
+    commands::{
+        dtm::DTM, gbs::GBS, gga::GGA, gll::GLL, gns::GNS, grs::GRS, gsa::GSA, gst::GST,
+        gsv::GSVPage, hdg::HDG, hdm::HDM, hdt::HDT, rlm::RLM, rmc::RMC, rot::ROT, ths::THS,
+        txt::TXT, vlw::VLW, vtg::VTG, zda::ZDA,
+    },
+    encoder::{encode, Encode, Formatted},
+    parser::Parser,
+    types::{CommandTypes, Cordinate, Date, InlineString, TalkerIds, Time},
+};
+
+/// One sentence of every encodable type
+const SAMPLES: &[&str] = &[
+    "$GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,*62",
+    "$GPGGA,161009.00,1122.20418,N,02339.35234,E,2,08,1.09,11.5,M,11.3,M,1.5,0120*48",
+    "$GLGSV,1,1,02,65,38,039,31,66,71,324,29,1*72",
+    "$GPGSV,3,3,10,31,43,292,41,32,02,186,*7D",
+    "$GPGSV,1,1,00*79",
+    "$GPGLL,4717.11437,N,00833.91522,E,083559.00,A,A*6B",
+    "$GPGSA,A,3,05,12,25,,,,,,,,,,2.1,1.2,1.7*35",
+    "$GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A*57",
+    "$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06",
+    "$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F",
+    "$GPGBS,235458.00,1.4,1.3,3.1,03,0.0,-21.4,3.8*75",
+    "$GNGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10.5,,,0.0,*67",
+    "$GPZDA,023012.00,01,03,2024,-05,00*4A",
+    "$GPGST,082356.00,1.8,0.9,0.6,45.0,0.8,0.7,1.4*60",
+    "$GPGRS,082356.00,1,0.5,-12.8,,,,,,,,,,*57",
+    "$GPTXT,01,01,02,ANTSTATUS=OK*3B",
+    "$GPTHS,77.52,A*30",
+    "$HEHDT,274.07,T*19",
+    "$HCHDG,98.3,0.0,E,12.6,W*57",
+    "$HCHDM,271.2,M*2F",
+    "$HEROT,-0.3,A*05",
+    "$VWVLW,,,,,1.00,N,1.00,N*4C",
+    "$GARLM,9C6AE2A50BFBFF0,083559.00,1,8000*08",
+];
+
+fn round_trip<T: Encode>(talker_id: &TalkerIds, sentence: &T) -> CommandTypes {
+    let line = encode(talker_id, sentence);
+    assert!(line.ends_with("\r\n"), "{:?}", line);
+    Parser::parse_line(line.trim_end()).unwrap_or_else(|e| panic!("{:?} {}", line, e))
+}
+
+fn encode_and_parse(talker_id: &TalkerIds, body: &CommandTypes) -> CommandTypes {
+    match body {
+        CommandTypes::GGA(e) => round_trip(talker_id, e),
+        CommandTypes::GSV(e) => round_trip(talker_id, e),
+        CommandTypes::GLL(e) => round_trip(talker_id, e),
+        CommandTypes::GSA(e) => round_trip(talker_id, e),
+        CommandTypes::RMC(e) => round_trip(talker_id, e),
+        CommandTypes::VTG(e) => round_trip(talker_id, e),
+        CommandTypes::DTM(e) => round_trip(talker_id, e),
+        CommandTypes::GBS(e) => round_trip(talker_id, e),
+        CommandTypes::GNS(e) => round_trip(talker_id, e),
+        CommandTypes::ZDA(e) => round_trip(talker_id, e),
+        CommandTypes::GST(e) => round_trip(talker_id, e),
+        CommandTypes::GRS(e) => round_trip(talker_id, e),
+        CommandTypes::TXT(e) => round_trip(talker_id, e),
+        CommandTypes::THS(e) => round_trip(talker_id, e),
+        CommandTypes::HDT(e) => round_trip(talker_id, e),
+        CommandTypes::HDG(e) => round_trip(talker_id, e),
+        CommandTypes::HDM(e) => round_trip(talker_id, e),
+        CommandTypes::ROT(e) => round_trip(talker_id, e),
+        CommandTypes::VLW(e) => round_trip(talker_id, e),
+        CommandTypes::RLM(e) => round_trip(talker_id, e),
+        e => panic!("{:?} is not encodable", e),
+    }
+}
+
+#[test]
+fn samples_round_trip() {
+    for sample in SAMPLES {
+        let sentence =
+            Parser::parse_sentence(sample).unwrap_or_else(|e| panic!("{} {}", sample, e));
+        assert_eq!(
+            encode_and_parse(&sentence.talker_id, &sentence.body),
+            sentence.body,
+            "{}",
+            sample
+        );
+    }
+}
+
+#[test]
+fn samples_keep_formatting() {
+    for sample in [
+        "$GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,*62",
+        "$GPGSA,A,3,05,12,25,,,,,,,,,,2.1,1.2,1.7*35",
+        "$GPZDA,023012.00,01,03,2024,-05,00*4A",
+        "$GLGSV,1,1,02,65,38,039,31,66,71,324,29,1*72",
+    ] {
+        let sentence = Parser::parse_sentence(sample).unwrap();
+        let line = match &sentence.body {
+            CommandTypes::GGA(e) => encode(&sentence.talker_id, e),
+            CommandTypes::GSA(e) => encode(&sentence.talker_id, e),
+            CommandTypes::ZDA(e) => encode(&sentence.talker_id, e),
+            CommandTypes::GSV(e) => encode(&sentence.talker_id, e),
+            _ => unreachable!(),
+        };
+        assert_eq!(line, format!("{}\r\n", sample));
+    }
+}
+
+/// Lines formatted differently from the standard format
+const UNUSUAL: &[&str] = &[
+    "GPGGA,161009.5,1122.2041,N,02339.3523,E,2,8,1.090,11.5,M,11.3,M,0.0,0120",
+    "GPGGA,161009,1122.20418,N,02339.35234,E,1,08,1.09,11.50,M,11.3,M,,",
+    "GLGSV,1,1,2,65,38,39,31,66,71,324,29,1",
+    "GPGLL,4717.1,N,833.91522,E,083559,A,A",
+    "GPGSA,A,3,5,12,25,,,,,,,,,,2.10,1.2,1.7",
+    "GPRMC,083559.00,A,4717.11437,N,00833.91522,E,.004,077.52,091202,,,A",
+    "GPVTG,77.520,T,,M,0.0040,N,0.008,K,A",
+    "GPDTM,W84,,0,N,0,E,0,W84",
+    "GPGBS,235458.00,1.40,1.3,3.1,3,0,-21.4,3.8",
+    "GNGNS,112257.00,3844.2401,N,908.43828,W,AN,3,10.50,,,0,",
+    "GPZDA,023012,1,3,2024,-5,0",
+    "GPGST,082356.00,1.80,0.9,0.6,45,0.8,0.7,1.4",
+    "GPGRS,082356.00,1,0.50,-12.8,,,,,,,,,,",
+    "GPTXT,1,1,2,ANTSTATUS=OK, with comma",
+    "GPTHS,77.5200,A",
+    "HEHDT,274.070,T",
+    "HCHDG,98.30,0,E,12.6,W",
+    "HCHDM,271.20,M",
+    "HEROT,-0.30,A",
+    "VWVLW,,,,,1,N,1,N",
+    "GARLM,9c6ae2a50bfbff0,083559,1,8000",
+];
+
+fn with_checksum(start: char, body: &str, lowercase: bool) -> String {
+    let checksum = body.bytes().fold(0, |checksum, byte| checksum ^ byte);
+    if lowercase {
+        format!("{}{}*{:02x}", start, body, checksum)
+    } else {
+        format!("{}{}*{:02X}", start, body, checksum)
+    }
+}
+
+fn formatted<T: Encode + rust_nmea::types::Command<T> + Default>(line: &str) -> Formatted<T> {
+    Formatted::parse(line).unwrap_or_else(|e| panic!("{} {}", line, e))
+}
+
+fn reencode(line: &str) -> String {
+    match Parser::parse_sentence(line).unwrap().body {
+        CommandTypes::GGA(_) => formatted::<GGA>(line).to_line(),
+        CommandTypes::GSV(_) => formatted::<GSVPage>(line).to_line(),
+        CommandTypes::GLL(_) => formatted::<GLL>(line).to_line(),
+        CommandTypes::GSA(_) => formatted::<GSA>(line).to_line(),
+        CommandTypes::RMC(_) => formatted::<RMC>(line).to_line(),
+        CommandTypes::VTG(_) => formatted::<VTG>(line).to_line(),
+        CommandTypes::DTM(_) => formatted::<DTM>(line).to_line(),
+        CommandTypes::GBS(_) => formatted::<GBS>(line).to_line(),
+        CommandTypes::GNS(_) => formatted::<GNS>(line).to_line(),
+        CommandTypes::ZDA(_) => formatted::<ZDA>(line).to_line(),
+        CommandTypes::GST(_) => formatted::<GST>(line).to_line(),
+        CommandTypes::GRS(_) => formatted::<GRS>(line).to_line(),
+        CommandTypes::TXT(_) => formatted::<TXT>(line).to_line(),
+        CommandTypes::THS(_) => formatted::<THS>(line).to_line(),
+        CommandTypes::HDT(_) => formatted::<HDT>(line).to_line(),
+        CommandTypes::HDG(_) => formatted::<HDG>(line).to_line(),
+        CommandTypes::HDM(_) => formatted::<HDM>(line).to_line(),
+        CommandTypes::ROT(_) => formatted::<ROT>(line).to_line(),
+        CommandTypes::VLW(_) => formatted::<VLW>(line).to_line(),
+        CommandTypes::RLM(_) => formatted::<RLM>(line).to_line(),
+        e => panic!("{:?} is not encodable", e),
+    }
+}
+
+#[test]
+fn unmodified_lines_are_byte_identical() {
+    let bodies = SAMPLES
+        .iter()
+        .map(|sample| &sample[1..sample.len() - 3])
+        .chain(UNUSUAL.iter().copied());
+    for body in bodies {
+        for (start, lowercase) in [('$', false), ('$', true), ('!', false)] {
+            let line = with_checksum(start, body, lowercase);
+            assert_eq!(reencode(&line), format!("{}\r\n", line));
+        }
+    }
+}
+
+#[test]
+fn modified_fields_use_standard_format() {
+    let line = with_checksum(
+        '$',
+        "GPGGA,161009.5,1122.2041,N,02339.3523,E,2,8,1.090,11.5,M,11.3,M,0.0,0120",
+        true,
+    );
+    let mut gga = formatted::<GGA>(&line);
+    gga.sentence.number_of_satellites = Some(9);
+    gga.sentence.differential_age_of_position = Some(0.5);
+    let expected = with_checksum(
+        '$',
+        "GPGGA,161009.5,1122.2041,N,02339.3523,E,2,09,1.090,11.5,M,11.3,M,0.5,0120",
+        true,
+    );
+    assert_eq!(gga.to_line(), format!("{}\r\n", expected));
+    assert_eq!(
+        Parser::parse_line(&expected),
+        Ok(CommandTypes::GGA(gga.sentence))
+    );
+
+    let line = with_checksum('$', "GPTXT,1,1,2,ANTSTATUS=OK, with comma", false);
+    let mut txt = formatted::<TXT>(&line);
+    txt.sentence.text_identifier = 1;
+    let expected = with_checksum('$', "GPTXT,01,01,01,ANTSTATUS=OK^2C with comma", false);
+    assert_eq!(txt.to_line(), format!("{}\r\n", expected));
+}
+
+/// Sentences of a receiver without a fix, position fields are empty
+const NO_FIX: &[&str] = &[
+    "$GPGGA,123519,,,,,0,00,,,M,,M,,*6B",
+    "$GPRMC,123519,V,,,,,,,230394,,,N*51",
+    "$GPGLL,,,,,123519,V,N*69",
+];
+
+#[test]
+fn no_fix_sentences_parse() {
+    for line in NO_FIX {
+        assert_eq!(reencode(line).trim_end(), *line);
+        match Parser::parse_line(line).unwrap() {
+            CommandTypes::GGA(gga) => {
+                assert!(gga.lat.is_none() && gga.lon.is_none() && gga.altitude.is_none());
+                assert_eq!(gga.number_of_satellites, Some(0));
+                assert!(gga.latitude().is_none());
+                assert_eq!(round_trip(&TalkerIds::GP, &gga), CommandTypes::GGA(gga));
+            }
+            CommandTypes::RMC(rmc) => {
+                assert!(rmc.lat.is_none() && rmc.speed_over_ground.is_none());
+                assert!(rmc.longitude().is_none());
+                assert_eq!(round_trip(&TalkerIds::GP, &rmc), CommandTypes::RMC(rmc));
+            }
+            CommandTypes::GLL(gll) => {
+                assert!(gll.lat.is_none() && gll.lon.is_none());
+                assert_eq!(round_trip(&TalkerIds::GP, &gll), CommandTypes::GLL(gll));
+            }
+            e => panic!("Unexpected {:?}", e),
+        }
+    }
+}
+
+#[test]
+fn fractional_seconds_are_exact() {
+    for (time, nanosecond, encoded) in [
+        ("123519", 0, "123519.00"),
+        ("123519.5", 500_000_000, "123519.50"),
+        ("123519.50", 500_000_000, "123519.50"),
+        ("123519.05", 50_000_000, "123519.05"),
+        ("123519.123", 123_000_000, "123519.123"),
+        ("123519.000000001", 1, "123519.000000001"),
+    ] {
+        let line = with_checksum('$', &format!("GPZDA,{},01,03,2024,00,00", time), false);
+        let zda = match Parser::parse_line(&line) {
+            Ok(CommandTypes::ZDA(zda)) => zda,
+            e => panic!("{} {:?}", line, e),
+        };
+        assert_eq!(zda.time.nanosecond, nanosecond, "{}", line);
+        let standard = encode(&TalkerIds::GP, &zda);
+        assert!(
+            standard.starts_with(&format!("$GPZDA,{},", encoded)),
+            "{}",
+            standard
+        );
+    }
+    let line = with_checksum('$', "GPZDA,123519.0000000001,01,03,2024,00,00", false);
+    assert!(Parser::parse_line(&line).is_err());
+}
+
+#[test]
+fn wrong_sentence_is_rejected() {
+    let line = "$GPZDA,023012.00,01,03,2024,-05,00*4A";
+    assert!(Formatted::<GGA>::parse(line).is_err());
+}
+
+fn time() -> impl Strategy<Value = Time> {
+    (0..24u8, 0..60u8, 0..61u8, 0..1_000_000_000u32).prop_map(
+        |(hour, minute, second, nanosecond)| Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        },
+    )
+}
+
+fn cordinate(degrees: usize) -> impl Strategy<Value = Cordinate> {
+    (0..degrees, 0.0..60.0f64).prop_map(|(degree, minute)| Cordinate { degree, minute })
+}
+
+proptest! {
+    #[test]
+    fn gga_round_trip(
+        time in proptest::option::of(time()),
+        lat in proptest::option::of(cordinate(90)),
+        lon in proptest::option::of(cordinate(180)),
+        satellites in proptest::option::of(0..100u8),
+        hdop in proptest::option::of(0.0..100.0f64),
+        altitude in proptest::option::of(-1000.0..10000.0f64),
+        age in proptest::option::of(0.0..100.0f32),
+        station in proptest::option::of(0..1024usize),
+    ) {
+        let line = "$GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,*62";
+        let Ok(CommandTypes::GGA(gga)) = Parser::parse_line(line) else { unreachable!() };
+        let gga = rust_nmea::commands::gga::GGA {
+            time,
+            lat,
+            lon,
+            number_of_satellites: satellites,
+            horizontal_dilution_of_position: hdop,
+            altitude,
+            differential_age_of_position: age,
+            differential_reference_station_id: station,
+            ..gga
+        };
+        prop_assert_eq!(round_trip(&TalkerIds::GN, &gga), CommandTypes::GGA(gga));
+    }
+
+    #[test]
+    fn gns_round_trip(
+        time in time(),
+        lat in proptest::option::of(cordinate(90)),
+        lon in proptest::option::of(cordinate(180)),
+        satellites in 0..100u8,
+        age in proptest::option::of(any::<f64>().prop_filter("finite", |e| e.is_finite())),
+    ) {
+        let gns = GNS {
+            time,
+            lat,
+            lon,
+            number_of_satellites_in_use: satellites,
+            age_of_differential_gps_data: age,
+            ..Default::default()
+        };
+        prop_assert_eq!(round_trip(&TalkerIds::GN, &gns), CommandTypes::GNS(gns));
+    }
+
+    #[test]
+    fn gsv_round_trip(
+        total_pages in 1..10usize,
+        page_id in 1..10usize,
+        in_view in 0..100usize,
+        satellites in proptest::collection::vec(
+            proptest::option::of(0..360usize),
+            16,
+        ),
+        signal_id in proptest::option::of(0..16usize),
+    ) {
+        let mut page = GSVPage {
+            total_pages,
+            page_id,
+            number_of_known_satellites_in_view: in_view,
+            signal_id,
+            ..Default::default()
+        };
+        for (satellite, fields) in page.satellites.iter_mut().zip(satellites.chunks(4)) {
+            satellite.id = fields[0];
+            satellite.elevation = fields[1];
+            satellite.azimuth = fields[2];
+            satellite.snr = fields[3];
+        }
+        prop_assert_eq!(round_trip(&TalkerIds::GP, &page), CommandTypes::GSV(page));
+    }
+
+    #[test]
+    fn zda_round_trip(
+        time in time(),
+        day in 1..32u8,
+        month in 1..13u8,
+        year in 0..10000usize,
+        local_zone_hours in -13..15i8,
+        local_zone_minutes in 0..60i8,
+    ) {
+        let local_zone_minutes = if local_zone_hours < 0 { -local_zone_minutes } else { local_zone_minutes };
+        let zda = ZDA {
+            time,
+            date: Date { day, month, year },
+            local_zone_hours,
+            local_zone_minutes,
+        };
+        prop_assert_eq!(round_trip(&TalkerIds::GP, &zda), CommandTypes::ZDA(zda));
+    }
+
+    #[test]
+    fn txt_round_trip(text in "[\\PC]{0,20}", text_identifier in 0..100u8) {
+        let txt = TXT {
+            total_sentences: 1,
+            sentence_number: 1,
+            text_identifier,
+            text: InlineString::try_from(text.as_str()).unwrap(),
+        };
+        prop_assert_eq!(round_trip(&TalkerIds::GP, &txt), CommandTypes::TXT(txt));
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "not below one second")]
+fn nanosecond_of_a_second_or_more_is_not_encoded() {
+    let zda = ZDA {
+        time: Time {
+            hour: 1,
+            minute: 2,
+            second: 3,
+            nanosecond: 1_500_000_000,
+        },
+        ..ZDA::default()
+    };
+    encode(&TalkerIds::GP, &zda);
+}
@@ -0,0 +1,103 @@
+//! Framing of raw serial input by `StreamDecoder`
+
+use rust_nmea::{
+    stream::StreamDecoder,
+    types::{CommandTypes, Error, ErrorKind, FramingError, Sentence},
+};
+
+const ZDA: &[u8] = b"$GPZDA,023012.00,01,03,2024,-05,00*4A";
+const GGA: &[u8] = b"$GPGGA,161009.00,1122.20418,N,02339.35234,E,1,08,1.09,11.5,M,11.3,M,,*62";
+
+fn decode(decoder: &mut StreamDecoder, bytes: &[u8]) -> Vec<Result<Sentence, Error>> {
+    decoder.sentences(bytes).collect()
+}
+
+fn ids(results: &[Result<Sentence, Error>]) -> Vec<Result<&str, ErrorKind>> {
+    results
+        .iter()
+        .map(|result| match result {
+            Ok(sentence) => Ok(match sentence.body {
+                CommandTypes::ZDA(_) => "ZDA",
+                CommandTypes::GGA(_) => "GGA",
+                _ => "other",
+            }),
+            Err(e) => Err(e.kind),
+        })
+        .collect()
+}
+
+#[test]
+fn concatenated_sentences_without_line_ending() {
+    let input = [ZDA, ZDA, b"\r\n"].concat();
+    let results = decode(&mut StreamDecoder::new(), &input);
+    assert_eq!(ids(&results), [Ok("ZDA"), Ok("ZDA")]);
+
+    let input = [GGA, ZDA, b"\n"].concat();
+    let results = decode(&mut StreamDecoder::new(), &input);
+    assert_eq!(ids(&results), [Ok("GGA"), Ok("ZDA")]);
+}
+
+#[test]
+fn truncated_sentence_is_incomplete() {
+    let input = [&GGA[..40], ZDA, b"\r\n"].concat();
+    let results = decode(&mut StreamDecoder::new(), &input);
+    assert_eq!(
+        ids(&results),
+        [Err(ErrorKind::Framing(FramingError::Incomplete)), Ok("ZDA")]
+    );
+}
+
+#[test]
+fn garbage_between_sentences_is_dropped() {
+    let input = [
+        b"\0\xff garbage\r\n".as_slice(),
+        ZDA,
+        b"\r\nnoise \x80\x81\n",
+        GGA,
+        b"\r\n\r\n",
+    ]
+    .concat();
+    let results = decode(&mut StreamDecoder::new(), &input);
+    assert_eq!(ids(&results), [Ok("ZDA"), Ok("GGA")]);
+}
+
+#[test]
+fn sentence_split_across_pushes() {
+    let input = [ZDA, b"\r\n", GGA, b"\r\n"].concat();
+    for chunk_size in 1..input.len() {
+        let mut decoder = StreamDecoder::new();
+        let results = input
+            .chunks(chunk_size)
+            .flat_map(|chunk| decode(&mut decoder, chunk))
+            .collect::<Vec<_>>();
+        assert_eq!(ids(&results), [Ok("ZDA"), Ok("GGA")], "{}", chunk_size);
+    }
+}
+
+#[test]
+fn split_checksum_is_not_completed_early() {
+    let mut decoder = StreamDecoder::new();
+    assert!(decode(&mut decoder, &ZDA[..ZDA.len() - 1]).is_empty());
+    assert!(decode(&mut decoder, &ZDA[ZDA.len() - 1..]).is_empty());
+    let results = decode(&mut decoder, b"\r\n");
+    assert_eq!(ids(&results), [Ok("ZDA")]);
+}
+
+#[test]
+fn too_long_sentence_is_dropped() {
+    let mut decoder = StreamDecoder::with_max_length(40);
+    let input = [GGA, b"\r\n", ZDA, b"\r\n"].concat();
+    let results = decode(&mut decoder, &input);
+    assert_eq!(
+        ids(&results),
+        [Err(ErrorKind::Framing(FramingError::TooLong)), Ok("ZDA")]
+    );
+}
+
+#[test]
+fn finish_parses_sentence_without_line_ending() {
+    let mut decoder = StreamDecoder::new();
+    assert!(decode(&mut decoder, ZDA).is_empty());
+    assert!(matches!(decoder.finish(), Some(Ok(_))));
+    assert!(decoder.finish().is_none());
+}